@@ -110,7 +110,7 @@ enum Commands {
 }
 
 mod output {
-    use akahu_client::Account;
+    use akahu_client::{Account, format_money};
     use anyhow::{Context, Result};
     use serde::Serialize;
 
@@ -157,16 +157,16 @@ mod output {
                 escape_csv(&account.name),
                 format!("{:?}", account.kind),
                 account.status,
-                account.balance.current,
+                format_money(account.balance.current, account.balance.currency),
                 account
                     .balance
                     .available
-                    .map(|b: rust_decimal::Decimal| b.to_string())
+                    .map(|b| format_money(b, account.balance.currency))
                     .unwrap_or_default(),
                 account
                     .balance
                     .limit
-                    .map(|l: rust_decimal::Decimal| l.to_string())
+                    .map(|l| format_money(l, account.balance.currency))
                     .unwrap_or_default()
             ));
         }
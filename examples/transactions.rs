@@ -144,8 +144,9 @@ fn parse_date(date_str: &str) -> Result<DateTime<Utc>> {
 }
 
 mod output {
-    use akahu_client::{PendingTransaction, Transaction};
+    use akahu_client::{PendingTransaction, Transaction, format_money};
     use anyhow::{Context, Result};
+    use iso_currency::Currency;
     use serde::Serialize;
 
     pub fn format_json<T: Serialize>(items: &T) -> Result<String> {
@@ -199,10 +200,9 @@ mod output {
                 "{},{},{},{},{:?},{}\n",
                 tx.date.format("%Y-%m-%d"),
                 escape_csv(&tx.description),
-                tx.amount,
+                format_money(tx.amount, Currency::NZD),
                 tx.balance
-                    .as_ref()
-                    .map(|b: &rust_decimal::Decimal| b.to_string())
+                    .map(|b| format_money(b, Currency::NZD))
                     .unwrap_or_default(),
                 tx.kind,
                 tx.account.as_str()
@@ -255,7 +255,7 @@ mod output {
                 "{},{},{},{:?},{}\n",
                 tx.date.format("%Y-%m-%d"),
                 escape_csv(&tx.description),
-                tx.amount,
+                format_money(tx.amount, Currency::NZD),
                 tx.kind,
                 tx.account.as_str()
             ));
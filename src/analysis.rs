@@ -0,0 +1,556 @@
+//! Client-side analysis of fetched transactions.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use rust_decimal::Decimal;
+
+use crate::{PendingTransaction, Transaction, TransactionId, TransactionKind};
+
+/// How far apart two transactions may be dated and still be considered duplicates.
+///
+/// Banks sometimes re-post a transaction on the following day, so this is wider
+/// than the day-level accuracy of most transaction dates.
+const DUPLICATE_WINDOW_DAYS: i64 = 1;
+
+/// How far apart a pending transaction and its settled counterpart may be dated.
+///
+/// Pending transactions usually settle within a few business days, and the
+/// settled date can differ from the pending one in either direction.
+const SETTLEMENT_WINDOW_DAYS: i64 = 5;
+
+/// Find pairs of transactions that look like duplicates of each other.
+///
+/// Two transactions are flagged when they belong to the same account, have
+/// identical amounts, are dated within a day of each other, and have similar
+/// descriptions (equal after normalising case and punctuation, or one a prefix
+/// of the other). If both have been enriched with different merchants they are
+/// never flagged, since the same amount at two merchants is a coincidence rather
+/// than a duplicate.
+///
+/// Each pair is returned once, in the order the transactions appear in the input.
+pub fn find_duplicate_candidates(
+    transactions: &[Transaction],
+) -> Vec<(TransactionId, TransactionId)> {
+    let mut candidates = Vec::new();
+
+    for (index, first) in transactions.iter().enumerate() {
+        for second in transactions.iter().skip(index).skip(1) {
+            if is_duplicate_candidate(first, second) {
+                candidates.push((first.id.clone(), second.id.clone()));
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Whether two transactions look like duplicates of each other.
+fn is_duplicate_candidate(first: &Transaction, second: &Transaction) -> bool {
+    if first.id == second.id || first.account != second.account || first.amount != second.amount {
+        return false;
+    }
+
+    let gap = first.date.signed_duration_since(second.date).abs();
+    if gap > chrono::Duration::days(DUPLICATE_WINDOW_DAYS) {
+        return false;
+    }
+
+    let first_merchant = first.enriched_data.as_ref().map(|data| &data.merchant.id);
+    let second_merchant = second.enriched_data.as_ref().map(|data| &data.merchant.id);
+    if matches!((first_merchant, second_merchant), (Some(a), Some(b)) if a != b) {
+        return false;
+    }
+
+    similar_descriptions(&first.description, &second.description)
+}
+
+/// Whether two descriptions are equal after normalising, or one is a prefix of
+/// the other.
+fn similar_descriptions(first: &str, second: &str) -> bool {
+    let first = normalise_description(first);
+    let second = normalise_description(second);
+    first.starts_with(&second) || second.starts_with(&first)
+}
+
+/// Lowercase a description and collapse everything but letters and digits into
+/// single spaces.
+fn normalise_description(description: &str) -> String {
+    description
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Match pending transactions to the settled transactions they likely became.
+///
+/// Pending transactions have no ID, so they are matched on account, amount,
+/// description (as for [`find_duplicate_candidates`]) and a date within a few
+/// days of each other. Use this to avoid counting a purchase twice when showing
+/// pending and settled transactions together.
+///
+/// Returns `(index into pending, settled transaction ID)` pairs. Each settled
+/// transaction is matched at most once; if several could match, the one dated
+/// closest to the pending transaction is chosen.
+pub fn reconcile(
+    pending: &[PendingTransaction],
+    settled: &[Transaction],
+) -> Vec<(usize, TransactionId)> {
+    let mut matched = vec![false; settled.len()];
+    let mut pairs = Vec::new();
+
+    for (pending_index, pending) in pending.iter().enumerate() {
+        let best = settled
+            .iter()
+            .zip(matched.iter())
+            .enumerate()
+            .filter(|(_, (transaction, already_matched))| {
+                !**already_matched && is_settlement_of(pending, transaction)
+            })
+            .min_by_key(|(_, (transaction, _))| {
+                transaction.date.signed_duration_since(pending.date).abs()
+            })
+            .map(|(settled_index, (transaction, _))| (settled_index, transaction.id.clone()));
+
+        if let Some((settled_index, id)) = best {
+            if let Some(slot) = matched.get_mut(settled_index) {
+                *slot = true;
+            }
+            pairs.push((pending_index, id));
+        }
+    }
+
+    pairs
+}
+
+/// Whether a settled transaction looks like the settlement of a pending one.
+fn is_settlement_of(pending: &PendingTransaction, settled: &Transaction) -> bool {
+    pending.account == settled.account
+        && pending.amount == settled.amount
+        && settled.date.signed_duration_since(pending.date).abs()
+            <= chrono::Duration::days(SETTLEMENT_WINDOW_DAYS)
+        && similar_descriptions(&pending.description, &settled.description)
+}
+
+/// The direction to sort transactions in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest, earliest or alphabetically first values first.
+    Ascending,
+    /// Largest, latest or alphabetically last values first.
+    Descending,
+}
+
+impl SortOrder {
+    /// Apply the direction to an ascending comparison.
+    const fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// Sort transactions by date.
+///
+/// The sort is stable, so transactions with the same date keep their relative order.
+pub fn sort_by_date(transactions: &mut [Transaction], order: SortOrder) {
+    transactions.sort_by(|a, b| order.apply(a.date.cmp(&b.date)));
+}
+
+/// Sort transactions by signed amount, so the largest debits sort before credits
+/// when ascending.
+///
+/// The sort is stable, so transactions with the same amount keep their relative order.
+pub fn sort_by_amount(transactions: &mut [Transaction], order: SortOrder) {
+    transactions.sort_by(|a, b| order.apply(a.amount.cmp(&b.amount)));
+}
+
+/// Sort transactions by enriched merchant name, ignoring case.
+///
+/// Transactions without enrichment data are placed at the end in either order.
+/// The sort is stable, so transactions with the same merchant keep their relative
+/// order.
+pub fn sort_by_merchant(transactions: &mut [Transaction], order: SortOrder) {
+    fn merchant_name(transaction: &Transaction) -> Option<&str> {
+        transaction
+            .enriched_data
+            .as_ref()
+            .map(|data| data.merchant.name.as_str())
+    }
+
+    transactions.sort_by(|a, b| match (merchant_name(a), merchant_name(b)) {
+        (Some(a), Some(b)) => order.apply(
+            a.chars()
+                .flat_map(char::to_lowercase)
+                .cmp(b.chars().flat_map(char::to_lowercase)),
+        ),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+}
+
+/// Summary statistics over a list of transactions, computed in a single pass.
+///
+/// Collect an iterator of transactions (owned or borrowed) into this type:
+///
+/// ```
+/// # use akahu_client::{Transaction, TransactionStats};
+/// # fn example(transactions: &[Transaction]) {
+/// let stats: TransactionStats = transactions.iter().collect();
+/// println!("{} transactions, net {}", stats.count(), stats.net());
+/// # }
+/// ```
+///
+/// Credits and debits are distinguished by the sign of the amount, not by the
+/// transaction [kind](TransactionKind).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionStats {
+    /// Number of transactions.
+    count: usize,
+    /// Sum of all positive amounts.
+    total_in: Decimal,
+    /// Sum of the magnitudes of all negative amounts.
+    total_out: Decimal,
+    /// Smallest amount seen.
+    min: Option<Decimal>,
+    /// Largest amount seen.
+    max: Option<Decimal>,
+    /// Number of transactions of each kind.
+    by_kind: HashMap<TransactionKind, usize>,
+}
+
+impl TransactionStats {
+    /// The number of transactions.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The total of all credits (money in).
+    pub const fn total_in(&self) -> Decimal {
+        self.total_in
+    }
+
+    /// The total of all debits (money out), as a positive amount.
+    pub const fn total_out(&self) -> Decimal {
+        self.total_out
+    }
+
+    /// Money in minus money out.
+    pub fn net(&self) -> Decimal {
+        self.total_in.saturating_sub(self.total_out)
+    }
+
+    /// The smallest (most negative) amount, if there were any transactions.
+    pub const fn min(&self) -> Option<Decimal> {
+        self.min
+    }
+
+    /// The largest amount, if there were any transactions.
+    pub const fn max(&self) -> Option<Decimal> {
+        self.max
+    }
+
+    /// The number of transactions of each kind.
+    pub const fn by_kind(&self) -> &HashMap<TransactionKind, usize> {
+        &self.by_kind
+    }
+
+    /// Add a transaction to the statistics.
+    fn record(&mut self, transaction: &Transaction) {
+        let amount = transaction.amount;
+
+        self.count = self.count.saturating_add(1);
+        if amount.is_sign_negative() {
+            self.total_out = self.total_out.saturating_add(amount.abs());
+        } else {
+            self.total_in = self.total_in.saturating_add(amount);
+        }
+        self.min = Some(self.min.map_or(amount, |min| min.min(amount)));
+        self.max = Some(self.max.map_or(amount, |max| max.max(amount)));
+
+        let kind_count = self.by_kind.entry(transaction.kind.clone()).or_insert(0);
+        *kind_count = kind_count.saturating_add(1);
+    }
+}
+
+impl<'a> FromIterator<&'a Transaction> for TransactionStats {
+    fn from_iter<I: IntoIterator<Item = &'a Transaction>>(iter: I) -> Self {
+        let mut stats = Self::default();
+        for transaction in iter {
+            stats.record(transaction);
+        }
+        stats
+    }
+}
+
+impl FromIterator<Transaction> for TransactionStats {
+    fn from_iter<I: IntoIterator<Item = Transaction>>(iter: I) -> Self {
+        let mut stats = Self::default();
+        for transaction in iter {
+            stats.record(&transaction);
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        enriched_transaction_json, pending_transaction_json, transaction_json,
+    };
+
+    fn transaction_with_amount(
+        id: &str,
+        date: &str,
+        amount: f64,
+        merchant: Option<&str>,
+    ) -> Transaction {
+        let json = match merchant {
+            Some(merchant) => enriched_transaction_json(id, date, amount, merchant),
+            None => transaction_json(id, date, amount),
+        };
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn transaction(id: &str, date: &str, description: &str, merchant: Option<&str>) -> Transaction {
+        let mut transaction = transaction_with_amount(id, date, -42.5, merchant);
+        transaction.description = description.to_string();
+        transaction
+    }
+
+    #[test]
+    fn test_obvious_duplicate_pair() {
+        let transactions = [
+            transaction(
+                "trans_1",
+                "2025-01-01T00:00:00.000Z",
+                "THE WAREHOUSE",
+                Some("Warehouse"),
+            ),
+            transaction(
+                "trans_2",
+                "2025-01-02T00:00:00.000Z",
+                "The Warehouse Ltd",
+                Some("Warehouse"),
+            ),
+            transaction(
+                "trans_3",
+                "2025-01-09T00:00:00.000Z",
+                "THE WAREHOUSE",
+                Some("Warehouse"),
+            ),
+        ];
+
+        let candidates = find_duplicate_candidates(&transactions);
+
+        assert_eq!(
+            candidates,
+            [(
+                TransactionId::new("trans_1").unwrap(),
+                TransactionId::new("trans_2").unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_same_amount_different_merchant() {
+        let transactions = [
+            transaction(
+                "trans_1",
+                "2025-01-01T00:00:00.000Z",
+                "EFTPOS PURCHASE",
+                Some("Warehouse"),
+            ),
+            transaction(
+                "trans_2",
+                "2025-01-01T00:00:00.000Z",
+                "EFTPOS PURCHASE",
+                Some("Countdown"),
+            ),
+        ];
+
+        assert!(
+            find_duplicate_candidates(&transactions).is_empty(),
+            "different merchants should not be flagged"
+        );
+    }
+
+    fn sortable_transactions() -> Vec<Transaction> {
+        vec![
+            transaction_with_amount(
+                "trans_a",
+                "2025-01-02T00:00:00.000Z",
+                -10.0,
+                Some("countdown"),
+            ),
+            transaction_with_amount("trans_b", "2025-01-01T00:00:00.000Z", 50.0, None),
+            transaction_with_amount("trans_c", "2025-01-02T00:00:00.000Z", -10.0, Some("Air NZ")),
+            transaction_with_amount(
+                "trans_d",
+                "2025-01-03T00:00:00.000Z",
+                -99.0,
+                Some("Countdown"),
+            ),
+        ]
+    }
+
+    fn ids(transactions: &[Transaction]) -> Vec<&str> {
+        transactions
+            .iter()
+            .map(|transaction| transaction.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_by_date() {
+        let mut transactions = sortable_transactions();
+
+        sort_by_date(&mut transactions, SortOrder::Ascending);
+        assert_eq!(
+            ids(&transactions),
+            ["trans_b", "trans_a", "trans_c", "trans_d"]
+        );
+
+        sort_by_date(&mut transactions, SortOrder::Descending);
+        assert_eq!(
+            ids(&transactions),
+            ["trans_d", "trans_a", "trans_c", "trans_b"]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_amount() {
+        let mut transactions = sortable_transactions();
+
+        sort_by_amount(&mut transactions, SortOrder::Ascending);
+        assert_eq!(
+            ids(&transactions),
+            ["trans_d", "trans_a", "trans_c", "trans_b"]
+        );
+
+        sort_by_amount(&mut transactions, SortOrder::Descending);
+        assert_eq!(
+            ids(&transactions),
+            ["trans_b", "trans_a", "trans_c", "trans_d"]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_merchant() {
+        let mut transactions = sortable_transactions();
+
+        sort_by_merchant(&mut transactions, SortOrder::Ascending);
+        assert_eq!(
+            ids(&transactions),
+            ["trans_c", "trans_a", "trans_d", "trans_b"]
+        );
+
+        sort_by_merchant(&mut transactions, SortOrder::Descending);
+        assert_eq!(
+            ids(&transactions),
+            ["trans_a", "trans_d", "trans_c", "trans_b"]
+        );
+    }
+
+    fn pending_transaction(date: &str, description: &str) -> PendingTransaction {
+        let mut json = pending_transaction_json(date, -42.5);
+        json.as_object_mut()
+            .unwrap()
+            .insert("description".to_string(), description.into());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_reconcile_pending_with_settled() {
+        let pending = [
+            pending_transaction("2025-01-01T09:00:00.000Z", "COUNTDOWN METRO"),
+            pending_transaction("2025-01-01T10:00:00.000Z", "Z ENERGY"),
+        ];
+        let settled = [
+            transaction("trans_other", "2025-01-02T00:00:00.000Z", "NEW WORLD", None),
+            transaction(
+                "trans_countdown",
+                "2025-01-03T00:00:00.000Z",
+                "COUNTDOWN METRO AUCKLAND",
+                None,
+            ),
+        ];
+
+        assert_eq!(
+            reconcile(&pending, &settled),
+            vec![(0, TransactionId::new("trans_countdown").unwrap())],
+            "only the Countdown purchase has settled"
+        );
+    }
+
+    #[test]
+    fn test_reconcile_matches_each_settled_transaction_once() {
+        let pending = [
+            pending_transaction("2025-01-01T00:00:00.000Z", "COFFEE CO"),
+            pending_transaction("2025-01-04T00:00:00.000Z", "COFFEE CO"),
+        ];
+        let settled = [transaction(
+            "trans_coffee",
+            "2025-01-04T00:00:00.000Z",
+            "COFFEE CO",
+            None,
+        )];
+
+        assert_eq!(
+            reconcile(&pending, &settled),
+            vec![(0, TransactionId::new("trans_coffee").unwrap())],
+            "the settled transaction is only paired once"
+        );
+
+        let far_pending = [pending_transaction("2025-02-01T00:00:00.000Z", "COFFEE CO")];
+        assert!(
+            reconcile(&far_pending, &settled).is_empty(),
+            "a month apart is too far to be the same purchase"
+        );
+    }
+
+    #[test]
+    fn test_transaction_stats() {
+        let transactions: Vec<Transaction> = [
+            transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.25),
+            transaction_json("trans_2", "2025-01-02T00:00:00.000Z", 1500.0),
+            transaction_json("trans_3", "2025-01-03T00:00:00.000Z", -89.75),
+            transaction_json("trans_4", "2025-01-04T00:00:00.000Z", 0.5),
+        ]
+        .into_iter()
+        .map(|json| serde_json::from_value(json).unwrap())
+        .collect();
+
+        let stats: TransactionStats = transactions.iter().collect();
+
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.total_in(), "1500.5".parse().unwrap());
+        assert_eq!(stats.total_out(), "100".parse().unwrap());
+        assert_eq!(stats.net(), "1400.5".parse().unwrap());
+        assert_eq!(stats.min(), Some("-89.75".parse().unwrap()));
+        assert_eq!(stats.max(), Some("1500".parse().unwrap()));
+        assert_eq!(stats.by_kind().get(&TransactionKind::Debit), Some(&2));
+        assert_eq!(stats.by_kind().get(&TransactionKind::Credit), Some(&2));
+
+        let owned: TransactionStats = transactions.into_iter().collect();
+        assert_eq!(owned, stats, "owned and borrowed collection agree");
+    }
+
+    #[test]
+    fn test_transaction_stats_empty() {
+        let stats: TransactionStats = std::iter::empty::<Transaction>().collect();
+
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.net(), Decimal::ZERO);
+        assert_eq!(stats.min(), None);
+        assert!(stats.by_kind().is_empty());
+    }
+}
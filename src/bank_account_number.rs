@@ -73,6 +73,57 @@ pub enum BankPrefix {
 }
 
 impl BankPrefix {
+    /// Every known prefix, in numeric order.
+    const ALL: [Self; 27] = [
+        Self::Anz,
+        Self::Bnz,
+        Self::Westpac,
+        Self::AnzWise,
+        Self::ChinaConstruction,
+        Self::AnzNational,
+        Self::Nab,
+        Self::Icbc,
+        Self::AnzPostBank,
+        Self::Asb,
+        Self::WestpacTrust,
+        Self::WestpacOtago,
+        Self::Tsb,
+        Self::WestpacSouthland,
+        Self::WestpacBop,
+        Self::WestpacCanterbury,
+        Self::WestpacWaikato,
+        Self::WestpacWellington,
+        Self::WestpacWestland,
+        Self::WestpacSouthCant,
+        Self::WestpacAuckland,
+        Self::AsbPartner,
+        Self::AnzPartner,
+        Self::Hsbc,
+        Self::Citibank,
+        Self::Kiwibank,
+        Self::BankOfChina,
+    ];
+
+    /// Every known prefix, in numeric order.
+    pub const fn all() -> &'static [Self] {
+        &Self::ALL
+    }
+
+    /// The distinct banks behind the known prefixes, as `(name, prefix)` pairs.
+    ///
+    /// Banks with several prefixes (such as Westpac's regional ranges) appear
+    /// once, with their lowest prefix. Useful for building a bank selection list.
+    pub fn banks() -> Vec<(&'static str, &'static str)> {
+        let mut banks: Vec<(&'static str, &'static str)> = Vec::new();
+        for prefix in Self::all() {
+            let name = prefix.bank_name();
+            if !banks.iter().any(|(existing, _)| *existing == name) {
+                banks.push((name, prefix.as_str()));
+            }
+        }
+        banks
+    }
+
     /// Get the 2-digit bank prefix as a string (e.g., "01" for ANZ).
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -141,6 +192,74 @@ impl BankPrefix {
             Self::BankOfChina => "Bank of China",
         }
     }
+
+    /// Get the primary BIC/SWIFT code of the institution behind this prefix
+    /// (e.g., "ANZBNZ22" for ANZ), if known.
+    ///
+    /// Legacy and partner prefixes map to the code of the bank that now operates them.
+    pub const fn swift(&self) -> Option<&'static str> {
+        match self {
+            Self::Anz
+            | Self::AnzNational
+            | Self::AnzPostBank
+            | Self::AnzWise
+            | Self::AnzPartner => Some("ANZBNZ22"),
+            Self::Bnz | Self::Nab => Some("BKNZNZ22"),
+            Self::Westpac
+            | Self::WestpacTrust
+            | Self::WestpacOtago
+            | Self::WestpacSouthland
+            | Self::WestpacBop
+            | Self::WestpacCanterbury
+            | Self::WestpacWaikato
+            | Self::WestpacWellington
+            | Self::WestpacWestland
+            | Self::WestpacSouthCant
+            | Self::WestpacAuckland => Some("WPACNZ2W"),
+            Self::Asb | Self::AsbPartner => Some("ASBBNZ2A"),
+            Self::Kiwibank => Some("KIWINZ22"),
+            Self::Tsb => Some("TSBKNZ22"),
+            Self::ChinaConstruction => Some("PCBCNZ2A"),
+            Self::Icbc => Some("ICBKNZ2A"),
+            Self::Hsbc => Some("HSBCNZ2A"),
+            Self::Citibank => Some("CITINZ2X"),
+            Self::BankOfChina => Some("BKCHNZ2A"),
+        }
+    }
+
+    /// Whether this prefix is the primary prefix of a registered bank, as opposed
+    /// to a legacy, regional or partner range operated by another bank.
+    pub const fn is_registered_bank(&self) -> bool {
+        match self {
+            Self::Anz
+            | Self::Bnz
+            | Self::Westpac
+            | Self::ChinaConstruction
+            | Self::Icbc
+            | Self::Asb
+            | Self::Tsb
+            | Self::Hsbc
+            | Self::Citibank
+            | Self::Kiwibank
+            | Self::BankOfChina => true,
+            Self::AnzWise
+            | Self::Nab
+            | Self::AnzNational
+            | Self::AnzPostBank
+            | Self::AnzPartner
+            | Self::WestpacTrust
+            | Self::WestpacOtago
+            | Self::WestpacSouthland
+            | Self::WestpacBop
+            | Self::WestpacCanterbury
+            | Self::WestpacWaikato
+            | Self::WestpacWellington
+            | Self::WestpacWestland
+            | Self::WestpacSouthCant
+            | Self::WestpacAuckland
+            | Self::AsbPartner => false,
+        }
+    }
 }
 
 impl FromStr for BankPrefix {
@@ -221,50 +340,42 @@ impl std::fmt::Display for BankPrefix {
 /// - XXX: 3-digit suffix
 ///
 /// The account number is always stored in formatted form with hyphens, even if provided
-/// without them during construction.
+/// without them during construction. Deserialization goes through the same validation,
+/// so the accessors never see a malformed value.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct BankAccountNumber(String);
+#[serde(try_from = "String", into = "String")]
+pub struct BankAccountNumber {
+    /// The formatted account number.
+    value: String,
+    /// The bank identified by the first two digits.
+    prefix: BankPrefix,
+}
 
 impl BankAccountNumber {
     /// Create a new bank account number with format validation.
     pub fn new<T: Into<String>>(value: T) -> Result<Self, InvalidBankAccountError> {
         let s = value.into();
-        let validate_parts = |parts: &[&str]| -> Result<(), ()> {
-            if parts.len() != 4 {
-                return Err(());
-            }
-            let Some(bank_code) = parts.get(0) else {
-                return Err(());
-            };
-            let Some(branch) = parts.get(1) else {
-                return Err(());
-            };
-            let Some(account) = parts.get(2) else {
-                return Err(());
-            };
-            let Some(suffix) = parts.get(3) else {
+        let validate_parts = |parts: &[&str]| -> Result<BankPrefix, ()> {
+            let [bank_code, branch, account, suffix] = parts else {
                 return Err(());
             };
 
-            if BankPrefix::from_str(bank_code).is_err() {
-                return Err(());
-            }
+            let prefix = BankPrefix::from_str(bank_code)?;
             if branch.len() != 4 || account.len() != 7 || suffix.len() != 3 {
                 return Err(());
             }
             if !parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit())) {
                 return Err(());
             }
-            Ok(())
+            Ok(prefix)
         };
 
         if s.contains('-') {
             let parts: Vec<&str> = s.split('-').collect();
-            if validate_parts(&parts).is_err() {
+            let Ok(prefix) = validate_parts(&parts) else {
                 return Err(InvalidBankAccountError(s));
-            }
-            Ok(Self(s))
+            };
+            Ok(Self { value: s, prefix })
         } else {
             if s.len() != 16 || !s.chars().all(|c| c.is_ascii_digit()) {
                 return Err(InvalidBankAccountError(s));
@@ -284,50 +395,101 @@ impl BankAccountNumber {
                 .ok_or_else(|| InvalidBankAccountError(s.clone()))?;
 
             let parts = vec![bank_code, branch, account, suffix];
-            if validate_parts(&parts).is_err() {
+            let Ok(prefix) = validate_parts(&parts) else {
                 return Err(InvalidBankAccountError(s));
-            }
+            };
             let formatted = format!("{}-{}-{}-{}", bank_code, branch, account, suffix);
-            Ok(Self(formatted))
+            Ok(Self {
+                value: formatted,
+                prefix,
+            })
         }
     }
 
+    /// Get one of the hyphen-separated components.
+    ///
+    /// Validation guarantees all four components exist, so the empty fallback is
+    /// never observed.
+    fn part(&self, index: usize) -> &str {
+        self.value.split('-').nth(index).unwrap_or_default()
+    }
+
     /// Returns the Bank Prefix enum.
-    pub fn prefix(&self) -> BankPrefix {
-        BankPrefix::from_str(self.bank_code()).expect("Invalid prefix in stored account number")
+    pub const fn prefix(&self) -> BankPrefix {
+        self.prefix
     }
 
     /// Returns the 2-digit bank code string (e.g., "01").
     pub fn bank_code(&self) -> &str {
-        self.0
-            .get(0..2)
-            .expect("bank code is always present in validated account number")
+        self.part(0)
     }
 
     /// Returns the 4-digit branch code string (e.g., "0123").
     pub fn branch_code(&self) -> &str {
-        self.0
-            .get(3..7)
-            .expect("branch code is always present in validated account number")
+        self.part(1)
     }
 
     /// Returns the 7-digit account base number string (e.g., "0012345").
     pub fn account_number(&self) -> &str {
-        self.0
-            .get(8..15)
-            .expect("account number is always present in validated account number")
+        self.part(2)
     }
 
     /// Returns the 3-digit suffix string (e.g., "000").
     pub fn suffix(&self) -> &str {
-        self.0
-            .get(16..19)
-            .expect("suffix is always present in validated account number")
+        self.part(3)
+    }
+
+    /// Returns the bank and branch codes, e.g. `("01", "0123")`.
+    ///
+    /// Together these identify the branch the account was opened at, and can be
+    /// used as a key when looking up branch details such as
+    /// [`BranchInfo`](crate::BranchInfo). Akahu has no branch lookup endpoint, so
+    /// the lookup itself is up to the caller.
+    pub fn branch_info_hint(&self) -> (&str, &str) {
+        (self.bank_code(), self.branch_code())
     }
 
     /// Returns the full string representation.
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.value
+    }
+
+    /// Format the number for display with the account base hidden, e.g.
+    /// `01-2345-*******-000`.
+    ///
+    /// The bank, branch and suffix are kept so users can still tell their
+    /// accounts apart.
+    pub fn masked(&self) -> String {
+        format!(
+            "{}-{}-{}-{}",
+            self.bank_code(),
+            self.branch_code(),
+            mask(self.account_number()),
+            self.suffix()
+        )
+    }
+
+    /// Format the number for display with everything but the suffix hidden, e.g.
+    /// `**-****-*******-000`.
+    pub fn masked_suffix_only(&self) -> String {
+        format!(
+            "{}-{}-{}-{}",
+            mask(self.bank_code()),
+            mask(self.branch_code()),
+            mask(self.account_number()),
+            self.suffix()
+        )
+    }
+}
+
+/// Replace every character of `part` with `*`.
+fn mask(part: &str) -> String {
+    "*".repeat(part.len())
+}
+
+impl From<BankAccountNumber> for String {
+    fn from(value: BankAccountNumber) -> Self {
+        value.value
     }
 }
 
@@ -357,20 +519,113 @@ impl TryFrom<&str> for BankAccountNumber {
 
 impl std::fmt::Display for BankAccountNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.value)
     }
 }
 
 impl AsRef<str> for BankAccountNumber {
     fn as_ref(&self) -> &str {
-        &self.0
+        &self.value
     }
 }
 
 impl std::ops::Deref for BankAccountNumber {
     type Target = str;
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.value
+    }
+}
+
+/// An account number as reported by a financial institution.
+///
+/// Most institutions report a full NZ bank account number, but some mask part
+/// of it (e.g. `12-****-****567-00`) or use an identifier in another format
+/// entirely, such as a credit card or KiwiSaver member number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum FormattedAccount {
+    /// A valid NZ bank account number.
+    Bank(BankAccountNumber),
+    /// An account number with some digits hidden.
+    Masked(String),
+    /// Any other identifier.
+    Other(String),
+}
+
+impl FormattedAccount {
+    /// Characters institutions use to hide digits.
+    const MASK_CHARACTERS: [char; 4] = ['*', 'x', 'X', '•'];
+
+    /// Classify an account number string.
+    pub fn new<T: Into<String>>(value: T) -> Self {
+        let value = value.into();
+        if value.contains(Self::MASK_CHARACTERS) {
+            return Self::Masked(value);
+        }
+        match BankAccountNumber::new(value) {
+            Ok(account) => Self::Bank(account),
+            Err(InvalidBankAccountError(value)) => Self::Other(value),
+        }
+    }
+
+    /// The bank account number, if this is a full NZ account number.
+    pub const fn bank_account(&self) -> Option<&BankAccountNumber> {
+        match self {
+            Self::Bank(account) => Some(account),
+            Self::Masked(_) | Self::Other(_) => None,
+        }
+    }
+
+    /// Whether some digits of this account number are hidden.
+    pub const fn is_masked(&self) -> bool {
+        matches!(self, Self::Masked(_))
+    }
+
+    /// Returns the account number as reported.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Bank(account) => account.as_str(),
+            Self::Masked(value) | Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<String> for FormattedAccount {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for FormattedAccount {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<BankAccountNumber> for FormattedAccount {
+    fn from(value: BankAccountNumber) -> Self {
+        Self::Bank(value)
+    }
+}
+
+impl From<FormattedAccount> for String {
+    fn from(value: FormattedAccount) -> Self {
+        match value {
+            FormattedAccount::Bank(account) => account.into(),
+            FormattedAccount::Masked(value) | FormattedAccount::Other(value) => value,
+        }
+    }
+}
+
+impl std::fmt::Display for FormattedAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl AsRef<str> for FormattedAccount {
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
 }
 
@@ -391,6 +646,30 @@ mod tests {
         assert_eq!(account.suffix(), "000");
     }
 
+    #[test]
+    fn test_masked() {
+        let account = BankAccountNumber::new("01-2345-6789012-000").expect("Should be valid");
+
+        assert_eq!(account.masked(), "01-2345-*******-000");
+        assert_eq!(account.masked_suffix_only(), "**-****-*******-000");
+
+        let account = BankAccountNumber::new("12-3456-7890123-001").expect("Should be valid");
+        assert_eq!(account.masked(), "12-3456-*******-001");
+        assert!(
+            FormattedAccount::new(account.masked()).is_masked(),
+            "masked output should be recognised as masked"
+        );
+    }
+
+    #[test]
+    fn test_branch_info_hint() {
+        let account = BankAccountNumber::new("01-0123-6789012-000").expect("Should be valid");
+        assert_eq!(account.branch_info_hint(), ("01", "0123"));
+
+        let unformatted = BankAccountNumber::new("3890000000000123").expect("Should be valid");
+        assert_eq!(unformatted.branch_info_hint(), ("38", "9000"));
+    }
+
     #[test]
     fn test_component_extraction_from_unformatted() {
         // Unformatted input should result in correctly formatted output and extraction
@@ -423,4 +702,101 @@ mod tests {
 
         assert_eq!(account.as_str(), reconstructed);
     }
+
+    #[test]
+    fn test_swift_codes() {
+        assert_eq!(BankPrefix::Anz.swift(), Some("ANZBNZ22"));
+        assert_eq!(BankPrefix::AnzPostBank.swift(), Some("ANZBNZ22"));
+        assert_eq!(BankPrefix::WestpacAuckland.swift(), Some("WPACNZ2W"));
+        assert_eq!(BankPrefix::Kiwibank.swift(), Some("KIWINZ22"));
+        assert_eq!(BankPrefix::BankOfChina.swift(), Some("BKCHNZ2A"));
+    }
+
+    #[test]
+    fn test_is_registered_bank() {
+        assert!(
+            BankPrefix::Asb.is_registered_bank(),
+            "ASB is a registered bank"
+        );
+        assert!(
+            !BankPrefix::AsbPartner.is_registered_bank(),
+            "ASB partner range is not a separate bank"
+        );
+        assert!(
+            !BankPrefix::WestpacOtago.is_registered_bank(),
+            "regional Westpac ranges are not separate banks"
+        );
+    }
+
+    #[test]
+    fn test_legacy_bnz_range_is_consistent() {
+        assert!(
+            !BankPrefix::Nab.is_registered_bank(),
+            "08 is a legacy range operated by BNZ"
+        );
+        assert_eq!(BankPrefix::Nab.swift(), BankPrefix::Bnz.swift());
+        assert_eq!(BankPrefix::Nab.bank_name(), BankPrefix::Bnz.bank_name());
+
+        let banks = BankPrefix::banks();
+        assert!(banks.contains(&("Bank of New Zealand", "02")));
+        assert!(banks.iter().all(|(_, prefix)| *prefix != "08"));
+    }
+
+    #[test]
+    fn test_all_prefixes() {
+        let parsed: Vec<BankPrefix> = (0_u8..=99)
+            .filter_map(|value| BankPrefix::try_from(value).ok())
+            .collect();
+
+        assert_eq!(
+            BankPrefix::all(),
+            parsed.as_slice(),
+            "all() should list every prefix in numeric order"
+        );
+    }
+
+    #[test]
+    fn test_banks_are_deduplicated() {
+        let banks = BankPrefix::banks();
+        let names: Vec<&str> = banks.iter().map(|(name, _)| *name).collect();
+
+        assert_eq!(
+            names,
+            [
+                "ANZ",
+                "Bank of New Zealand",
+                "Westpac",
+                "China Construction Bank",
+                "ICBC",
+                "ASB",
+                "TSB",
+                "HSBC",
+                "Citibank",
+                "Kiwibank",
+                "Bank of China",
+            ]
+        );
+        assert!(
+            banks.contains(&("Westpac", "03")),
+            "Westpac is listed under its primary prefix"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_validates() {
+        let account: BankAccountNumber =
+            serde_json::from_str("\"38-9000-0000000-123\"").expect("Should be valid");
+        assert_eq!(account.prefix(), BankPrefix::Kiwibank);
+        assert_eq!(
+            serde_json::to_string(&account).expect("Should serialize"),
+            "\"38-9000-0000000-123\""
+        );
+
+        // Previously this deserialized and then panicked in the accessors.
+        let result = serde_json::from_str::<BankAccountNumber>("\"12-34\"");
+        assert!(
+            result.is_err(),
+            "malformed account numbers should be rejected"
+        );
+    }
 }
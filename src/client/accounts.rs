@@ -2,11 +2,15 @@
 //!
 //! This module contains methods for managing user accounts connected to your Akahu application.
 
-use crate::{AccountId, UserToken};
+use crate::{AccountId, AuthorizationId, UserToken};
 
 use super::AkahuClient;
 use reqwest::Method;
 
+#[allow(
+    clippy::same_name_method,
+    reason = "AkahuApi mirrors these methods so callers need not import the trait"
+)]
 impl AkahuClient {
     /// Get a list of all accounts that the user has connected to your application.
     ///
@@ -38,12 +42,44 @@ impl AkahuClient {
         self.execute_request(req).await
     }
 
+    /// Get the user's accounts that can currently be used as a payment source.
+    ///
+    /// This fetches all accounts with [`get_accounts`](Self::get_accounts) and keeps
+    /// only those that are [active](crate::models::Active::Active) and have the
+    /// [`PaymentFrom`](crate::models::Attribute::PaymentFrom) attribute, which is
+    /// useful for populating a "pay from" selection.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    ///
+    /// # Returns
+    ///
+    /// The accounts payments can be made from, in the order Akahu returned them.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_accounts>]
+    pub async fn payment_source_accounts(
+        &self,
+        user_token: &UserToken,
+    ) -> crate::error::AkahuResult<Vec<crate::models::Account>> {
+        let accounts = self.get_accounts(user_token).await?;
+
+        Ok(accounts
+            .items
+            .into_iter()
+            .filter(|account| {
+                matches!(account.status, crate::models::Active::Active) && account.can_pay_from()
+            })
+            .collect())
+    }
+
     /// Get a specific account by its ID.
     ///
     /// # Arguments
     ///
     /// * `user_token` - The user's access token obtained through OAuth
-    /// * `account_id` - The unique identifier for the account (prefixed with `acc_`)
+    /// * `account_id` - The unique identifier for the account (prefixed with `acc_`),
+    ///   or the [`Account`](crate::Account) itself
     ///
     /// # Returns
     ///
@@ -52,12 +88,71 @@ impl AkahuClient {
     /// Access the account via the `.item` field.
     ///
     /// [<https://developers.akahu.nz/reference/get_accounts-id>]
-    pub async fn get_account(
+    pub async fn get_account<Id: AsRef<AccountId> + ?Sized>(
         &self,
         user_token: &UserToken,
-        account_id: &AccountId,
+        account_id: &Id,
     ) -> crate::error::AkahuResult<crate::models::ItemResponse<crate::models::Account>> {
-        let uri = format!("accounts/{}", account_id.as_str());
+        let uri = format!("accounts/{}", account_id.as_ref().as_str());
+
+        let headers = self.build_user_headers(user_token)?;
+
+        let req = self
+            .client
+            .request(Method::GET, format!("{}/{}", self.base_url, uri))
+            .headers(headers)
+            .build()?;
+
+        self.execute_request(req).await
+    }
+
+    /// Get the user's authorisations, each with the accounts it grants access to.
+    ///
+    /// Akahu does not list authorisations directly, so this fetches the user's
+    /// accounts and groups them by [`Account::authorisation`](crate::models::Account::authorisation).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    ///
+    /// # Returns
+    ///
+    /// The authorisations, in the order their first account was returned.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_accounts>]
+    pub async fn get_authorisations(
+        &self,
+        user_token: &UserToken,
+    ) -> crate::error::AkahuResult<Vec<crate::models::Authorisation>> {
+        let accounts = self.get_accounts(user_token).await?;
+        Ok(crate::models::Authorisation::group(accounts.items))
+    }
+
+    /// Get a specific account, along with the JSON it was returned as.
+    ///
+    /// Behaves like [`get_account`](Self::get_account), but also keeps the raw
+    /// account object for auditing or forwarding, without a second request.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `account_id` - The unique identifier for the account (prefixed with `acc_`),
+    ///   or the [`Account`](crate::Account) itself
+    ///
+    /// # Returns
+    ///
+    /// A response containing the typed account in `.item.value` and its JSON in
+    /// `.item.raw`.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_accounts-id>]
+    pub async fn get_account_with_raw<Id: AsRef<AccountId> + ?Sized>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> crate::error::AkahuResult<
+        crate::models::ItemResponse<crate::models::WithRaw<crate::models::Account>>,
+    > {
+        let uri = format!("accounts/{}", account_id.as_ref().as_str());
 
         let headers = self.build_user_headers(user_token)?;
 
@@ -76,7 +171,7 @@ impl AkahuClient {
     /// Accounts connected via official open banking cannot be revoked on an individual basis.
     /// Instead, you must either:
     /// - Direct users through the OAuth flow to adjust permissions with their bank
-    /// - Use the Revoke Access To Authorisation endpoint to revoke the entire authorization
+    /// - Use [`revoke_authorisation`](Self::revoke_authorisation) to revoke the entire authorization
     ///
     /// # Arguments
     ///
@@ -90,14 +185,54 @@ impl AkahuClient {
     ///
     /// [<https://developers.akahu.nz/reference/delete_accounts-id>]
     #[deprecated(
-        note = "This endpoint is deprecated for accounts with official open banking connections. Use the Revoke Access To Authorisation endpoint instead."
+        note = "This endpoint is deprecated for accounts with official open banking connections. Use `revoke_authorisation` instead."
     )]
-    pub async fn revoke_account_access(
+    pub async fn revoke_account_access<Id: AsRef<AccountId> + ?Sized>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> crate::error::AkahuResult<()> {
+        let uri = format!("accounts/{}", account_id.as_ref().as_str());
+
+        let headers = self.build_user_headers(user_token)?;
+
+        let req = self
+            .client
+            .request(Method::DELETE, format!("{}/{}", self.base_url, uri))
+            .headers(headers)
+            .build()?;
+
+        // This endpoint returns empty response on success
+        let res = self.send_request(req).await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            self.handle_error_response(res).await
+        }
+    }
+
+    /// Revoke your application's access to every account in an authorisation.
+    ///
+    /// This is the supported way to remove access to accounts with official open
+    /// banking connections, which cannot be revoked individually.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `authorisation_id` - The authorisation to revoke (prefixed with `auth_`)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on successful revocation.
+    ///
+    /// [<https://developers.akahu.nz/reference/delete_authorisations-id>]
+    pub async fn revoke_authorisation(
         &self,
         user_token: &UserToken,
-        account_id: &AccountId,
+        authorisation_id: &AuthorizationId,
     ) -> crate::error::AkahuResult<()> {
-        let uri = format!("accounts/{}", account_id.as_str());
+        let uri = format!("authorisations/{}", authorisation_id.as_str());
 
         let headers = self.build_user_headers(user_token)?;
 
@@ -108,7 +243,7 @@ impl AkahuClient {
             .build()?;
 
         // This endpoint returns empty response on success
-        let res = self.client.execute(req).await?;
+        let res = self.send_request(req).await?;
 
         if res.status().is_success() {
             Ok(())
@@ -117,3 +252,155 @@ impl AkahuClient {
         }
     }
 }
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use crate::{
+        AccountId, AuthorizationId,
+        error::AkahuError,
+        test_utils::{account_json, mock_client, user_token},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    fn account(id: &str, status: &str, attributes: &[&str]) -> serde_json::Value {
+        let mut account = account_json(id);
+        let fields = account.as_object_mut().unwrap();
+        fields.insert("status".to_string(), status.into());
+        fields.insert("attributes".to_string(), attributes.into());
+        account
+    }
+
+    #[tokio::test]
+    async fn test_payment_source_accounts() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/accounts"))
+            .and(header("Authorization", "Bearer user_token_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [
+                    account("acc_payable", "ACTIVE", &["PAYMENT_FROM", "TRANSACTIONS"]),
+                    account("acc_receive_only", "ACTIVE", &["PAYMENT_TO"]),
+                    account("acc_inactive", "INACTIVE", &["PAYMENT_FROM"]),
+                    account("acc_also_payable", "ACTIVE", &["PAYMENT_FROM"]),
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let accounts = mock_client(&server)
+            .payment_source_accounts(&user_token())
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = accounts.iter().map(|account| account.id.as_str()).collect();
+        assert_eq!(ids, vec!["acc_payable", "acc_also_payable"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_account_with_raw() {
+        let server = MockServer::start().await;
+
+        let mut raw = account_json("acc_123");
+        raw.as_object_mut()
+            .unwrap()
+            .insert("unknown_field".to_string(), "kept".into());
+
+        Mock::given(method("GET"))
+            .and(path("/accounts/acc_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "item": raw
+            })))
+            .mount(&server)
+            .await;
+
+        let response = mock_client(&server)
+            .get_account_with_raw(&user_token(), &AccountId::new("acc_123").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.item.value.id.as_str(), "acc_123");
+        assert_eq!(response.item.raw, raw);
+    }
+
+    #[tokio::test]
+    async fn test_get_account_accepts_id_or_account() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/accounts/acc_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "item": account_json("acc_123")
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let account = client
+            .get_account(&user_token(), &AccountId::new("acc_123").unwrap())
+            .await
+            .unwrap()
+            .item;
+
+        let refetched = client
+            .get_account(&user_token(), &account)
+            .await
+            .unwrap()
+            .item;
+        assert_eq!(refetched, account);
+
+        let owned: AccountId = (&account).into();
+        let borrowed: &AccountId = (&account).into();
+        assert_eq!(&owned, borrowed);
+    }
+
+    #[tokio::test]
+    async fn test_revoke_authorisation() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/authorisations/auth_123"))
+            .and(header("Authorization", "Bearer user_token_123"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/authorisations/auth_missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "success": false,
+                "message": "Authorisation not found"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        client
+            .revoke_authorisation(&user_token(), &AuthorizationId::new("auth_123").unwrap())
+            .await
+            .unwrap();
+
+        let result = client
+            .revoke_authorisation(
+                &user_token(),
+                &AuthorizationId::new("auth_missing").unwrap(),
+            )
+            .await;
+        assert!(
+            matches!(result, Err(AkahuError::NotFound { .. })),
+            "expected NotFound, got {result:?}"
+        );
+    }
+}
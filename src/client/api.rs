@@ -0,0 +1,510 @@
+//! Trait abstraction over the Akahu API.
+//!
+//! This allows downstream code to be generic over the client, so a hand-written
+//! fake can be substituted in tests instead of mocking HTTP responses.
+
+use std::future::Future;
+
+use crate::{
+    AccountId, AuthorizationId, Cursor, PaginatedResponse, PendingTransaction, Transaction,
+    TransactionId, UserToken,
+    error::AkahuResult,
+    models::{Account, Authorisation, Category, ItemResponse, ListResponse, Party, User, WithRaw},
+};
+
+use super::AkahuClient;
+
+/// The Akahu API endpoints, as implemented by [`AkahuClient`].
+///
+/// Each method corresponds to a single API request and behaves like the
+/// [`AkahuClient`] method of the same name. Write code against `impl AkahuApi`
+/// to be able to swap in a fake implementation in your own tests.
+///
+/// Convenience helpers that combine several requests, such as
+/// [`AkahuClient::get_all_transactions`], are not part of this trait.
+pub trait AkahuApi {
+    /// See [`AkahuClient::get_me`].
+    fn get_me(&self, user_token: &UserToken) -> impl Future<Output = AkahuResult<User>> + Send;
+
+    /// See [`AkahuClient::get_accounts`].
+    fn get_accounts(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<ListResponse<Account>>> + Send;
+
+    /// See [`AkahuClient::get_account`].
+    fn get_account<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> impl Future<Output = AkahuResult<ItemResponse<Account>>> + Send;
+
+    /// See [`AkahuClient::get_transactions`].
+    fn get_transactions(
+        &self,
+        user_token: &UserToken,
+        start: Option<chrono::DateTime<chrono::Utc>>,
+        end: Option<chrono::DateTime<chrono::Utc>>,
+        cursor: Option<Cursor>,
+    ) -> impl Future<Output = AkahuResult<PaginatedResponse<Transaction>>> + Send;
+
+    /// See [`AkahuClient::get_account_transactions`].
+    fn get_account_transactions<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+        start: Option<chrono::DateTime<chrono::Utc>>,
+        end: Option<chrono::DateTime<chrono::Utc>>,
+        cursor: Option<Cursor>,
+    ) -> impl Future<Output = AkahuResult<PaginatedResponse<Transaction>>> + Send;
+
+    /// See [`AkahuClient::get_pending_transactions`].
+    fn get_pending_transactions(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<Vec<PendingTransaction>>> + Send;
+
+    /// See [`AkahuClient::get_account_pending_transactions`].
+    fn get_account_pending_transactions<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> impl Future<Output = AkahuResult<Vec<PendingTransaction>>> + Send;
+
+    /// See [`AkahuClient::get_transaction`].
+    fn get_transaction(
+        &self,
+        user_token: &UserToken,
+        transaction_id: &TransactionId,
+    ) -> impl Future<Output = AkahuResult<Transaction>> + Send;
+
+    /// See [`AkahuClient::get_categories`].
+    fn get_categories(&self) -> impl Future<Output = AkahuResult<ListResponse<Category>>> + Send;
+
+    /// See [`AkahuClient::get_parties`].
+    fn get_parties(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<ListResponse<Party>>> + Send;
+
+    /// See [`AkahuClient::get_account_parties`].
+    fn get_account_parties<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> impl Future<Output = AkahuResult<ListResponse<Party>>> + Send;
+
+    /// See [`AkahuClient::refresh_all_accounts`].
+    fn refresh_all_accounts(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<()>> + Send;
+
+    /// See [`AkahuClient::refresh_account_or_connection`].
+    fn refresh_account_or_connection<Id: AsRef<str> + Send>(
+        &self,
+        user_token: &UserToken,
+        id: Id,
+    ) -> impl Future<Output = AkahuResult<()>> + Send;
+
+    /// See [`AkahuClient::get_transactions_by_ids`].
+    fn get_transactions_by_ids(
+        &self,
+        user_token: &UserToken,
+        ids: &[TransactionId],
+    ) -> impl Future<Output = AkahuResult<Vec<Transaction>>> + Send;
+
+    /// See [`AkahuClient::get_account_with_raw`].
+    fn get_account_with_raw<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> impl Future<Output = AkahuResult<ItemResponse<WithRaw<Account>>>> + Send;
+
+    /// See [`AkahuClient::get_authorisations`].
+    fn get_authorisations(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<Vec<Authorisation>>> + Send;
+
+    /// See [`AkahuClient::revoke_authorisation`].
+    fn revoke_authorisation(
+        &self,
+        user_token: &UserToken,
+        authorisation_id: &AuthorizationId,
+    ) -> impl Future<Output = AkahuResult<()>> + Send;
+}
+
+impl AkahuApi for AkahuClient {
+    fn get_me(&self, user_token: &UserToken) -> impl Future<Output = AkahuResult<User>> + Send {
+        Self::get_me(self, user_token)
+    }
+
+    fn get_accounts(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<ListResponse<Account>>> + Send {
+        Self::get_accounts(self, user_token)
+    }
+
+    fn get_account<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> impl Future<Output = AkahuResult<ItemResponse<Account>>> + Send {
+        Self::get_account(self, user_token, account_id)
+    }
+
+    fn get_transactions(
+        &self,
+        user_token: &UserToken,
+        start: Option<chrono::DateTime<chrono::Utc>>,
+        end: Option<chrono::DateTime<chrono::Utc>>,
+        cursor: Option<Cursor>,
+    ) -> impl Future<Output = AkahuResult<PaginatedResponse<Transaction>>> + Send {
+        Self::get_transactions(self, user_token, start, end, cursor)
+    }
+
+    fn get_account_transactions<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+        start: Option<chrono::DateTime<chrono::Utc>>,
+        end: Option<chrono::DateTime<chrono::Utc>>,
+        cursor: Option<Cursor>,
+    ) -> impl Future<Output = AkahuResult<PaginatedResponse<Transaction>>> + Send {
+        Self::get_account_transactions(self, user_token, account_id, start, end, cursor)
+    }
+
+    fn get_pending_transactions(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<Vec<PendingTransaction>>> + Send {
+        Self::get_pending_transactions(self, user_token)
+    }
+
+    fn get_account_pending_transactions<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> impl Future<Output = AkahuResult<Vec<PendingTransaction>>> + Send {
+        Self::get_account_pending_transactions(self, user_token, account_id)
+    }
+
+    fn get_transaction(
+        &self,
+        user_token: &UserToken,
+        transaction_id: &TransactionId,
+    ) -> impl Future<Output = AkahuResult<Transaction>> + Send {
+        Self::get_transaction(self, user_token, transaction_id)
+    }
+
+    fn get_categories(&self) -> impl Future<Output = AkahuResult<ListResponse<Category>>> + Send {
+        Self::get_categories(self)
+    }
+
+    fn get_parties(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<ListResponse<Party>>> + Send {
+        Self::get_parties(self, user_token)
+    }
+
+    fn get_account_parties<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> impl Future<Output = AkahuResult<ListResponse<Party>>> + Send {
+        Self::get_account_parties(self, user_token, account_id)
+    }
+
+    fn refresh_all_accounts(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<()>> + Send {
+        Self::refresh_all_accounts(self, user_token)
+    }
+
+    fn refresh_account_or_connection<Id: AsRef<str> + Send>(
+        &self,
+        user_token: &UserToken,
+        id: Id,
+    ) -> impl Future<Output = AkahuResult<()>> + Send {
+        Self::refresh_account_or_connection(self, user_token, id)
+    }
+
+    fn get_transactions_by_ids(
+        &self,
+        user_token: &UserToken,
+        ids: &[TransactionId],
+    ) -> impl Future<Output = AkahuResult<Vec<Transaction>>> + Send {
+        Self::get_transactions_by_ids(self, user_token, ids)
+    }
+
+    fn get_account_with_raw<Id: AsRef<AccountId> + ?Sized + Sync>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> impl Future<Output = AkahuResult<ItemResponse<WithRaw<Account>>>> + Send {
+        Self::get_account_with_raw(self, user_token, account_id)
+    }
+
+    fn get_authorisations(
+        &self,
+        user_token: &UserToken,
+    ) -> impl Future<Output = AkahuResult<Vec<Authorisation>>> + Send {
+        Self::get_authorisations(self, user_token)
+    }
+
+    fn revoke_authorisation(
+        &self,
+        user_token: &UserToken,
+        authorisation_id: &AuthorizationId,
+    ) -> impl Future<Output = AkahuResult<()>> + Send {
+        Self::revoke_authorisation(self, user_token, authorisation_id)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::AkahuError,
+        test_utils::{account_json, mock_client, transaction_json, user_token},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    /// Code under test, written against the trait rather than the client.
+    async fn account_names(api: &impl AkahuApi) -> AkahuResult<Vec<String>> {
+        let accounts = api.get_accounts(&user_token()).await?;
+        Ok(accounts
+            .items
+            .into_iter()
+            .map(|account| account.name.into_inner())
+            .collect())
+    }
+
+    /// A fake that only knows about accounts.
+    struct FakeAkahu {
+        accounts: Vec<Account>,
+    }
+
+    fn not_faked<T>() -> AkahuResult<T> {
+        Err(AkahuError::NotFound {
+            message: "not faked".to_string(),
+        })
+    }
+
+    impl AkahuApi for FakeAkahu {
+        async fn get_me(&self, _: &UserToken) -> AkahuResult<User> {
+            not_faked()
+        }
+
+        async fn get_accounts(&self, _: &UserToken) -> AkahuResult<ListResponse<Account>> {
+            Ok(ListResponse {
+                success: true,
+                items: self.accounts.clone(),
+            })
+        }
+
+        async fn get_account<Id: AsRef<AccountId> + ?Sized + Sync>(
+            &self,
+            _: &UserToken,
+            _: &Id,
+        ) -> AkahuResult<ItemResponse<Account>> {
+            not_faked()
+        }
+
+        async fn get_transactions(
+            &self,
+            _: &UserToken,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: Option<Cursor>,
+        ) -> AkahuResult<PaginatedResponse<Transaction>> {
+            not_faked()
+        }
+
+        async fn get_account_transactions<Id: AsRef<AccountId> + ?Sized + Sync>(
+            &self,
+            _: &UserToken,
+            _: &Id,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: Option<chrono::DateTime<chrono::Utc>>,
+            _: Option<Cursor>,
+        ) -> AkahuResult<PaginatedResponse<Transaction>> {
+            not_faked()
+        }
+
+        async fn get_pending_transactions(
+            &self,
+            _: &UserToken,
+        ) -> AkahuResult<Vec<PendingTransaction>> {
+            not_faked()
+        }
+
+        async fn get_account_pending_transactions<Id: AsRef<AccountId> + ?Sized + Sync>(
+            &self,
+            _: &UserToken,
+            _: &Id,
+        ) -> AkahuResult<Vec<PendingTransaction>> {
+            not_faked()
+        }
+
+        async fn get_transaction(
+            &self,
+            _: &UserToken,
+            _: &TransactionId,
+        ) -> AkahuResult<Transaction> {
+            not_faked()
+        }
+
+        async fn get_categories(&self) -> AkahuResult<ListResponse<Category>> {
+            not_faked()
+        }
+
+        async fn get_parties(&self, _: &UserToken) -> AkahuResult<ListResponse<Party>> {
+            not_faked()
+        }
+
+        async fn get_account_parties<Id: AsRef<AccountId> + ?Sized + Sync>(
+            &self,
+            _: &UserToken,
+            _: &Id,
+        ) -> AkahuResult<ListResponse<Party>> {
+            not_faked()
+        }
+
+        async fn refresh_all_accounts(&self, _: &UserToken) -> AkahuResult<()> {
+            not_faked()
+        }
+
+        async fn refresh_account_or_connection<Id: AsRef<str> + Send>(
+            &self,
+            _: &UserToken,
+            _: Id,
+        ) -> AkahuResult<()> {
+            not_faked()
+        }
+
+        async fn get_transactions_by_ids(
+            &self,
+            _: &UserToken,
+            _: &[TransactionId],
+        ) -> AkahuResult<Vec<Transaction>> {
+            not_faked()
+        }
+
+        async fn get_account_with_raw<Id: AsRef<AccountId> + ?Sized + Sync>(
+            &self,
+            _: &UserToken,
+            _: &Id,
+        ) -> AkahuResult<ItemResponse<WithRaw<Account>>> {
+            not_faked()
+        }
+
+        async fn get_authorisations(&self, _: &UserToken) -> AkahuResult<Vec<Authorisation>> {
+            Ok(Authorisation::group(self.accounts.clone()))
+        }
+
+        async fn revoke_authorisation(
+            &self,
+            _: &UserToken,
+            _: &AuthorizationId,
+        ) -> AkahuResult<()> {
+            not_faked()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fake_implementation() {
+        let fake = FakeAkahu {
+            accounts: vec![serde_json::from_value(account_json("acc_123")).unwrap()],
+        };
+
+        assert_eq!(account_names(&fake).await.unwrap(), vec!["Everyday"]);
+    }
+
+    #[tokio::test]
+    async fn test_client_implementation() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/accounts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [account_json("acc_123")]
+            })))
+            .mount(&server)
+            .await;
+
+        let names = account_names(&mock_client(&server)).await.unwrap();
+
+        assert_eq!(names, vec!["Everyday"]);
+    }
+
+    /// Refresh a single account, written against the trait.
+    async fn refresh_account(api: &impl AkahuApi, account_id: &AccountId) -> AkahuResult<()> {
+        api.refresh_account_or_connection(&user_token(), account_id)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_client_refresh_through_trait() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/refresh/acc_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        refresh_account(&mock_client(&server), &AccountId::new("acc_123").unwrap())
+            .await
+            .unwrap();
+    }
+
+    /// Fetch an account's transactions by id and by account, through the trait.
+    async fn transactions_by_id_and_account(
+        api: &impl AkahuApi,
+        account: &Account,
+    ) -> AkahuResult<(Vec<Transaction>, Vec<Transaction>)> {
+        let by_id = api
+            .get_account_transactions(&user_token(), &account.id, None, None, None)
+            .await?;
+        let by_account = api
+            .get_account_transactions(&user_token(), account, None, None, None)
+            .await?;
+        Ok((by_id.items, by_account.items))
+    }
+
+    #[tokio::test]
+    async fn test_account_methods_accept_account_through_trait() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/accounts/acc_123/transactions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.0)],
+                "cursor": { "next": null }
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let account: Account = serde_json::from_value(account_json("acc_123")).unwrap();
+        let (by_id, by_account) = transactions_by_id_and_account(&mock_client(&server), &account)
+            .await
+            .unwrap();
+
+        assert_eq!(by_id, by_account);
+    }
+}
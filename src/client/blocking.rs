@@ -0,0 +1,174 @@
+//! Blocking client wrapper.
+//!
+//! This module contains a synchronous facade over [`AkahuClient`] for callers
+//! that are not running inside an async runtime. Like `reqwest::blocking`, every
+//! call is driven to completion on a tokio runtime owned by (or shared with) the
+//! wrapper, so the async client does not need to be duplicated.
+
+use crate::{
+    AccountId, Cursor, PaginatedResponse, PendingTransaction, Transaction, User, UserToken,
+    error::AkahuResult,
+    models::{Account, ItemResponse, ListResponse},
+};
+
+use super::AkahuClient;
+
+/// The runtime used to drive requests to completion.
+enum Executor {
+    /// A single-threaded runtime created and owned by the blocking client.
+    Owned(tokio::runtime::Runtime),
+    /// A handle to a multi-threaded runtime owned by the caller.
+    Shared(tokio::runtime::Handle),
+}
+
+impl Executor {
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        match self {
+            Self::Owned(runtime) => runtime.block_on(future),
+            Self::Shared(handle) => handle.block_on(future),
+        }
+    }
+}
+
+/// A synchronous wrapper around [`AkahuClient`].
+///
+/// Each method blocks the current thread until the underlying request completes.
+///
+/// **Note:** These methods must not be called from within an async context, as
+/// tokio does not allow blocking on a runtime from one of its own worker threads.
+pub struct AkahuClientBlocking {
+    /// The async client used to perform requests
+    inner: AkahuClient,
+    /// The runtime requests are executed on
+    executor: Executor,
+}
+
+impl AkahuClientBlocking {
+    /// Create a new blocking client backed by a dedicated single-threaded runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tokio runtime could not be created.
+    pub fn new(client: AkahuClient) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Self {
+            inner: client,
+            executor: Executor::Owned(runtime),
+        })
+    }
+
+    /// Create a new blocking client that reuses an existing runtime.
+    ///
+    /// The handle should belong to a multi-threaded runtime, since a
+    /// current-thread runtime can only drive IO from its own `block_on`.
+    pub const fn with_handle(client: AkahuClient, handle: tokio::runtime::Handle) -> Self {
+        Self {
+            inner: client,
+            executor: Executor::Shared(handle),
+        }
+    }
+
+    /// Get a reference to the wrapped async client.
+    pub const fn inner(&self) -> &AkahuClient {
+        &self.inner
+    }
+
+    /// Blocking version of [`AkahuClient::get_me`].
+    pub fn get_me(&self, user_token: &UserToken) -> AkahuResult<User> {
+        self.executor.block_on(self.inner.get_me(user_token))
+    }
+
+    /// Blocking version of [`AkahuClient::get_accounts`].
+    pub fn get_accounts(&self, user_token: &UserToken) -> AkahuResult<ListResponse<Account>> {
+        self.executor.block_on(self.inner.get_accounts(user_token))
+    }
+
+    /// Blocking version of [`AkahuClient::get_account`].
+    pub fn get_account<Id: AsRef<AccountId> + ?Sized>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> AkahuResult<ItemResponse<Account>> {
+        self.executor
+            .block_on(self.inner.get_account(user_token, account_id))
+    }
+
+    /// Blocking version of [`AkahuClient::get_transactions`].
+    pub fn get_transactions(
+        &self,
+        user_token: &UserToken,
+        start: Option<chrono::DateTime<chrono::Utc>>,
+        end: Option<chrono::DateTime<chrono::Utc>>,
+        cursor: Option<Cursor>,
+    ) -> AkahuResult<PaginatedResponse<Transaction>> {
+        self.executor
+            .block_on(self.inner.get_transactions(user_token, start, end, cursor))
+    }
+
+    /// Blocking version of [`AkahuClient::get_account_transactions`].
+    pub fn get_account_transactions<Id: AsRef<AccountId> + ?Sized>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+        start: Option<chrono::DateTime<chrono::Utc>>,
+        end: Option<chrono::DateTime<chrono::Utc>>,
+        cursor: Option<Cursor>,
+    ) -> AkahuResult<PaginatedResponse<Transaction>> {
+        self.executor.block_on(
+            self.inner
+                .get_account_transactions(user_token, account_id, start, end, cursor),
+        )
+    }
+
+    /// Blocking version of [`AkahuClient::get_pending_transactions`].
+    pub fn get_pending_transactions(
+        &self,
+        user_token: &UserToken,
+    ) -> AkahuResult<Vec<PendingTransaction>> {
+        self.executor
+            .block_on(self.inner.get_pending_transactions(user_token))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{APP_TOKEN, account_json, mock_client, user_token};
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    #[test]
+    fn test_get_accounts_blocking() {
+        let server_runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = server_runtime.block_on(async {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/accounts"))
+                .and(header("X-Akahu-Id", APP_TOKEN))
+                .and(header("Authorization", "Bearer user_token_123"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "items": [account_json("acc_123")]
+                })))
+                .mount(&server)
+                .await;
+            server
+        });
+
+        let client = AkahuClientBlocking::new(mock_client(&server)).unwrap();
+
+        let accounts = client.get_accounts(&user_token()).unwrap();
+
+        assert_eq!(accounts.items.len(), 1, "expected a single account");
+        assert_eq!(accounts.items.first().unwrap().id.as_str(), "acc_123");
+    }
+}
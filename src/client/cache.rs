@@ -0,0 +1,202 @@
+//! Conditional request caching using `ETag` and `If-None-Match`.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use reqwest::header::{AUTHORIZATION, HeaderValue};
+
+/// Identifies a cached response.
+///
+/// The authorization header is part of the key so responses fetched with one
+/// user's token are never served to another. It is stored marked as sensitive,
+/// so its `Debug` output does not reveal the token.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct CacheKey {
+    /// The full request URL, including the query string.
+    url: String,
+    /// The request's `Authorization` header, if any.
+    authorization: Option<HeaderValue>,
+}
+
+/// A response body and the `ETag` it was served with.
+#[derive(Debug, Clone)]
+pub(super) struct CachedResponse {
+    /// The entity tag to send back in `If-None-Match`.
+    pub(super) etag: HeaderValue,
+    /// The response body.
+    pub(super) body: String,
+}
+
+/// An in-memory cache of `GET` responses that carried an `ETag`.
+///
+/// When attached with [`AkahuClient::with_response_cache`](crate::AkahuClient::with_response_cache),
+/// the client sends `If-None-Match` for requests it has a cached response for,
+/// and reuses the cached body when Akahu replies `304 Not Modified`. This saves
+/// bandwidth for rarely-changing resources such as categories.
+///
+/// Clones share the same underlying storage, so one cache can be used by
+/// several clients.
+///
+/// The `Debug` output shows only the number of cached responses, never the
+/// tokens or bodies they were fetched with.
+#[derive(Clone, Default)]
+pub struct ResponseCache {
+    /// Cached responses keyed by request.
+    entries: Arc<Mutex<HashMap<CacheKey, CachedResponse>>>,
+}
+
+impl ResponseCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of cached responses.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Remove every cached response.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Look up a cached response.
+    pub(super) fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        self.lock().get(key).cloned()
+    }
+
+    /// Store a response.
+    pub(super) fn insert(&self, key: CacheKey, response: CachedResponse) {
+        self.lock().insert(key, response);
+    }
+
+    /// The key a request's response is cached under.
+    pub(super) fn key(req: &reqwest::Request) -> CacheKey {
+        CacheKey {
+            url: req.url().to_string(),
+            authorization: req.headers().get(AUTHORIZATION).map(|value| {
+                let mut value = value.clone();
+                value.set_sensitive(true);
+                value
+            }),
+        }
+    }
+
+    /// Lock the entries, recovering from a poisoned lock since the map is
+    /// always left in a consistent state.
+    fn lock(&self) -> MutexGuard<'_, HashMap<CacheKey, CachedResponse>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("entries", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        UserToken,
+        test_utils::{account_json, mock_client, user_token},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    async fn mount_accounts(server: &MockServer, full_responses: u64, not_modified: u64) {
+        Mock::given(method("GET"))
+            .and(path("/accounts"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .expect(not_modified)
+            .mount(server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/accounts"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(serde_json::json!({
+                        "success": true,
+                        "items": [account_json("acc_123")]
+                    })),
+            )
+            .expect(full_responses)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_not_modified_reuses_cached_body() {
+        let server = MockServer::start().await;
+        mount_accounts(&server, 1, 1).await;
+
+        let cache = ResponseCache::new();
+        let client = mock_client(&server).with_response_cache(cache.clone());
+
+        let first = client.get_accounts(&user_token()).await.unwrap();
+        let second = client.get_accounts(&user_token()).await.unwrap();
+
+        assert_eq!(first, second, "the cached body should be reused on 304");
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cache_is_not_shared_between_users() {
+        let server = MockServer::start().await;
+        mount_accounts(&server, 2, 0).await;
+
+        let client = mock_client(&server).with_response_cache(ResponseCache::new());
+
+        client.get_accounts(&user_token()).await.unwrap();
+        client
+            .get_accounts(&UserToken::new("other_user_token"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_debug_does_not_reveal_tokens() {
+        let server = MockServer::start().await;
+        mount_accounts(&server, 1, 0).await;
+
+        let cache = ResponseCache::new();
+        let client = mock_client(&server).with_response_cache(cache.clone());
+        client.get_accounts(&user_token()).await.unwrap();
+
+        let debug = format!("{cache:?}");
+
+        assert!(!debug.contains("user_token_123"), "token leaked: {debug}");
+        assert!(!debug.contains("acc_123"), "body leaked: {debug}");
+        assert!(
+            debug.contains("entries: 1"),
+            "entry count is shown: {debug}"
+        );
+
+        let key = cache.lock().keys().next().cloned().unwrap();
+        let debug = format!("{key:?}");
+        assert!(!debug.contains("user_token_123"), "token leaked: {debug}");
+    }
+}
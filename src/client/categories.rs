@@ -0,0 +1,143 @@
+//! Category endpoint implementations.
+//!
+//! This module contains methods for listing the NZFCC categories Akahu uses to
+//! enrich transactions. These are app-scoped endpoints, so the client must be
+//! configured with [`with_app_secret`](AkahuClient::with_app_secret).
+
+use crate::Category;
+
+use super::AkahuClient;
+use reqwest::Method;
+
+#[allow(
+    clippy::same_name_method,
+    reason = "AkahuApi mirrors these methods so callers need not import the trait"
+)]
+impl AkahuClient {
+    /// Get a list of all categories that Akahu may assign to transactions.
+    ///
+    /// # Returns
+    ///
+    /// A response containing every category. Access the categories via the
+    /// `.items` field.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_categories>]
+    pub async fn get_categories(
+        &self,
+    ) -> crate::error::AkahuResult<crate::models::ListResponse<Category>> {
+        const URI: &str = "categories";
+
+        let (headers, app_secret) = self.build_app_headers()?;
+
+        let req = self
+            .client
+            .request(Method::GET, format!("{}/{}", self.base_url, URI))
+            .headers(headers)
+            .basic_auth(self.app_id_token.as_str(), Some(app_secret.as_str()))
+            .build()?;
+
+        self.execute_request(req).await
+    }
+
+    /// Get the categories belonging to a personal finance group.
+    ///
+    /// Akahu does not support filtering categories server-side, so this fetches
+    /// every category and filters the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The personal finance group to return categories for
+    ///
+    /// # Returns
+    ///
+    /// The categories in the given group.
+    pub async fn get_categories_in_group(
+        &self,
+        group: &nzfcc::CategoryGroup,
+    ) -> crate::error::AkahuResult<Vec<Category>> {
+        let categories = self.get_categories().await?;
+
+        Ok(categories
+            .items
+            .into_iter()
+            .filter(|category| category.groups.personal_finance.name == *group)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use crate::{
+        error::AkahuError,
+        test_utils::{APP_TOKEN, mock_client},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    fn category_json(name: &str, group: &str) -> serde_json::Value {
+        serde_json::json!({
+            "_id": "nzfcc_123",
+            "name": name,
+            "groups": {
+                "personal_finance": { "_id": "group_123", "name": group }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_categories_in_group() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/categories"))
+            .and(header("X-Akahu-Id", APP_TOKEN))
+            // base64("app_token_123:app_secret_123")
+            .and(header(
+                "Authorization",
+                "Basic YXBwX3Rva2VuXzEyMzphcHBfc2VjcmV0XzEyMw==",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [
+                    category_json("Cafes and restaurants", "Lifestyle"),
+                    category_json("Supermarkets and grocery stores", "Food"),
+                    category_json("General retail stores", "Lifestyle")
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let categories = mock_client(&server)
+            .with_app_secret("app_secret_123")
+            .get_categories_in_group(&nzfcc::CategoryGroup::Lifestyle)
+            .await
+            .unwrap();
+
+        let names: Vec<_> = categories.iter().map(|category| category.name).collect();
+        assert_eq!(
+            names,
+            [
+                nzfcc::NzfccCode::CafesAndRestaurants,
+                nzfcc::NzfccCode::GeneralRetailStores
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_categories_requires_app_secret() {
+        let server = MockServer::start().await;
+
+        let result = mock_client(&server).get_categories().await;
+
+        assert!(
+            matches!(result, Err(AkahuError::MissingAppSecret)),
+            "expected MissingAppSecret, got {result:?}"
+        );
+    }
+}
@@ -1,72 +1,137 @@
 //! Core helper methods for the Akahu client.
 
-use crate::UserToken;
+use crate::{AppSecret, QueryParams, UserToken};
 
 use super::AkahuClient;
-use reqwest::{
-    StatusCode,
-    header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue},
-};
+use reqwest::header::{ACCEPT, AUTHORIZATION, ETAG, HeaderMap, HeaderValue, IF_NONE_MATCH};
 
 /// Custom HTTP header name for Akahu application ID
 const AKAHU_ID_HEADER: &str = "X-Akahu-Id";
 
+/// Build the URL for `path` under `base`, encoding `params` as the query string.
+///
+/// Parameters are appended in the order returned by
+/// [`QueryParams::to_pairs`], and no `?` is added if there are none.
+pub(super) fn build_url(
+    base: &str,
+    path: &str,
+    params: &impl QueryParams,
+) -> crate::error::AkahuResult<reqwest::Url> {
+    let mut url = reqwest::Url::parse(&format!("{base}/{path}"))?;
+    let pairs = params.to_pairs();
+    if !pairs.is_empty() {
+        url.query_pairs_mut().extend_pairs(pairs);
+    }
+    Ok(url)
+}
+
 impl AkahuClient {
+    /// Send a request, recording metrics for it if the `metrics` feature is enabled
+    /// and notifying the [`Observer`](super::Observer) if one is set.
+    ///
+    /// Waits first if a [rate limit](AkahuClient::with_rate_limit) is configured.
+    pub(super) async fn send_request(
+        &self,
+        req: reqwest::Request,
+    ) -> crate::error::AkahuResult<reqwest::Response> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        #[cfg(feature = "metrics")]
+        let timer = super::metrics::RequestTimer::start(&self.base_url, &req);
+
+        let started = self.observer.as_ref().map(|observer| {
+            observer.on_request(req.method(), req.url().path());
+            std::time::Instant::now()
+        });
+
+        let res = self.client.execute(req).await;
+
+        #[cfg(feature = "metrics")]
+        timer.finish(res.as_ref().ok().map(reqwest::Response::status));
+
+        if let (Some(observer), Some(started)) = (&self.observer, started) {
+            observer.on_response(
+                res.as_ref().ok().map(reqwest::Response::status),
+                started.elapsed(),
+            );
+        }
+
+        Ok(res?)
+    }
+
     /// Execute a request and handle the response, converting HTTP errors to AkahuError
+    ///
+    /// If a [`ResponseCache`](super::ResponseCache) is configured, `GET` requests
+    /// are revalidated with `If-None-Match` and a `304 Not Modified` reuses the
+    /// cached body.
     pub(super) async fn execute_request<T: serde::de::DeserializeOwned>(
         &self,
-        req: reqwest::Request,
+        mut req: reqwest::Request,
     ) -> crate::error::AkahuResult<T> {
-        let res = self.client.execute(req).await?;
+        let cache = self
+            .response_cache
+            .as_ref()
+            .filter(|_| req.method() == reqwest::Method::GET);
+        let cache_key = cache.map(|_| super::ResponseCache::key(&req));
+        let cached = cache
+            .zip(cache_key.as_ref())
+            .and_then(|(cache, key)| cache.get(key));
+        if let Some(cached) = &cached {
+            req.headers_mut().insert(IF_NONE_MATCH, cached.etag.clone());
+        }
+
+        let res = self.send_request(req).await?;
+
+        if res.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                return Self::deserialize_body(cached.body);
+            }
+        }
 
         if res.status().is_success() {
+            let etag = res.headers().get(ETAG).cloned();
             let text = res.text().await?;
-            // Try to deserialize into the expected type T
-            let deserialized: T = serde_json::from_str(&text).map_err(|e| {
-                crate::error::AkahuError::JsonDeserialization {
-                    error: e,
-                    source_string: Some(text),
-                }
-            })?;
-            Ok(deserialized)
+
+            if let (Some(cache), Some(key), Some(etag)) = (cache, cache_key, etag) {
+                cache.insert(
+                    key,
+                    super::cache::CachedResponse {
+                        etag,
+                        body: text.clone(),
+                    },
+                );
+            }
+
+            Self::deserialize_body(text)
         } else {
             self.handle_error_response(res).await
         }
     }
 
+    /// Deserialize a successful response body into the expected type.
+    fn deserialize_body<T: serde::de::DeserializeOwned>(
+        text: String,
+    ) -> crate::error::AkahuResult<T> {
+        serde_json::from_str(&text).map_err(|e| crate::error::AkahuError::JsonDeserialization {
+            error: e,
+            source_string: Some(text),
+        })
+    }
+
     /// Parse error response and map to appropriate AkahuError variant
     pub(super) async fn handle_error_response<T>(
         &self,
         res: reqwest::Response,
     ) -> crate::error::AkahuResult<T> {
         let status = res.status();
+        let body = res.bytes().await.unwrap_or_default();
 
-        // Try to parse error message from response body
-        let message = match res.json::<crate::models::ErrorResponse>().await {
-            Ok(error_body) => error_body.message,
-            Err(_) => status
-                .canonical_reason()
-                .unwrap_or("Unknown error")
-                .to_string(),
-        };
-
-        Err(match status {
-            StatusCode::BAD_REQUEST => crate::error::AkahuError::BadRequest {
-                message,
-                status: StatusCode::BAD_REQUEST.as_u16(),
-            },
-            StatusCode::UNAUTHORIZED => crate::error::AkahuError::Unauthorized { message },
-            StatusCode::FORBIDDEN => crate::error::AkahuError::Forbidden { message },
-            StatusCode::NOT_FOUND => crate::error::AkahuError::NotFound { message },
-            StatusCode::TOO_MANY_REQUESTS => crate::error::AkahuError::RateLimited { message },
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                crate::error::AkahuError::InternalServerError { message }
-            }
-            _ => crate::error::AkahuError::ApiError {
-                status: status.as_u16(),
-                message,
-            },
-        })
+        Err(crate::error::AkahuError::from_status_and_body(
+            status.as_u16(),
+            &body,
+        ))
     }
 
     /// Build standard headers for user-scoped requests
@@ -83,4 +148,66 @@ impl AkahuClient {
         headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
         Ok(headers)
     }
+
+    /// Build standard headers for app-scoped requests.
+    ///
+    /// App-scoped endpoints authenticate with HTTP Basic auth using the app token
+    /// and secret, so the returned secret must be passed to
+    /// [`basic_auth`](reqwest::RequestBuilder::basic_auth) alongside these headers.
+    pub(super) fn build_app_headers(&self) -> crate::error::AkahuResult<(HeaderMap, &AppSecret)> {
+        let app_secret = self
+            .app_secret
+            .as_ref()
+            .ok_or(crate::error::AkahuError::MissingAppSecret)?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AKAHU_ID_HEADER, HeaderValue::from_str(&self.app_id_token)?);
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        Ok((headers, app_secret))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::{Cursor, TransactionQueryParams};
+
+    #[test]
+    fn test_build_url_without_params() {
+        let url = build_url(
+            "https://api.akahu.io/v1",
+            "transactions",
+            &TransactionQueryParams::new(),
+        )
+        .unwrap();
+
+        assert_eq!(url.as_str(), "https://api.akahu.io/v1/transactions");
+    }
+
+    #[test]
+    fn test_build_url_encodes_params() {
+        let query = TransactionQueryParams::new()
+            .start("2025-01-01T00:00:00Z".parse().unwrap())
+            .end("2025-01-31T23:59:59.999Z".parse().unwrap());
+        let cursor = Cursor::new("a+b/c=");
+
+        let url = build_url(
+            "https://api.akahu.io/v1",
+            "accounts/acc_123/transactions",
+            &(&query, Some(&cursor)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.akahu.io/v1/accounts/acc_123/transactions\
+             ?start=2025-01-01T00%3A00%3A00.000Z\
+             &end=2025-01-31T23%3A59%3A59.999Z\
+             &cursor=a%2Bb%2Fc%3D"
+        );
+    }
 }
@@ -0,0 +1,153 @@
+//! Dashboard Endpoints
+//!
+//! This module contains methods that combine several endpoints into the data a
+//! dashboard typically needs.
+
+use crate::{DashboardSnapshot, TransactionQueryParams, UserToken};
+
+use super::AkahuClient;
+
+impl AkahuClient {
+    /// Fetch a user's accounts, recent settled transactions and pending
+    /// transactions together.
+    ///
+    /// The three are requested concurrently, so at most three requests are in
+    /// flight at once. Settled transactions are fetched with
+    /// [`get_all_transactions`](Self::get_all_transactions), whose pages are
+    /// requested one after another. If any request fails, the first error is
+    /// returned and the others are cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `days` - How many days of settled transactions to fetch, counting back
+    ///   from now. If this is too large to represent, every transaction your app
+    ///   has access to is fetched.
+    ///
+    /// # Returns
+    ///
+    /// A [`DashboardSnapshot`] of the user's accounts and transactions.
+    pub async fn get_dashboard(
+        &self,
+        user_token: &UserToken,
+        days: u32,
+    ) -> crate::error::AkahuResult<DashboardSnapshot> {
+        let fetched_at = chrono::Utc::now();
+
+        let mut query = TransactionQueryParams::new().end(fetched_at);
+        query.start = chrono::TimeDelta::try_days(i64::from(days))
+            .and_then(|period| fetched_at.checked_sub_signed(period));
+
+        let (accounts, transactions, pending) = tokio::try_join!(
+            self.get_accounts(user_token),
+            self.get_all_transactions(user_token, &query),
+            self.get_pending_transactions(user_token),
+        )?;
+
+        Ok(DashboardSnapshot {
+            accounts: accounts.items,
+            transactions,
+            pending,
+            fetched_at,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use crate::test_utils::{
+        account_json, mock_client, pending_transaction_json, transaction_json, user_token,
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path, query_param},
+    };
+
+    #[tokio::test]
+    async fn test_get_dashboard() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/accounts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [account_json("acc_123")]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("cursor", "cursor_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_2", "2025-01-02T00:00:00.000Z", 5.0)],
+                "cursor": { "next": null }
+            })))
+            .with_priority(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.0)],
+                "cursor": { "next": "cursor_2" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions/pending"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [pending_transaction_json("2025-01-03T00:00:00.000Z", -4.5)]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let snapshot = client.get_dashboard(&user_token(), 30).await.unwrap();
+
+        assert_eq!(snapshot.accounts.len(), 1);
+        assert_eq!(
+            snapshot
+                .transactions
+                .iter()
+                .map(|transaction| transaction.id.as_str())
+                .collect::<Vec<_>>(),
+            ["trans_1", "trans_2"]
+        );
+        assert_eq!(snapshot.pending.len(), 1);
+
+        let requests = server.received_requests().await.unwrap();
+        let first_page = requests
+            .iter()
+            .find(|request| {
+                request.url.path() == "/transactions" && request.url.query_pairs().count() == 2
+            })
+            .unwrap();
+        let start: chrono::DateTime<chrono::Utc> = first_page
+            .url
+            .query_pairs()
+            .find(|(key, _)| key == "start")
+            .unwrap()
+            .1
+            .parse()
+            .unwrap();
+        let period = snapshot.fetched_at.signed_duration_since(start);
+        assert!(
+            (period - chrono::TimeDelta::days(30)).abs() < chrono::TimeDelta::seconds(1),
+            "transactions should cover the last 30 days, not {period}"
+        );
+    }
+}
@@ -7,6 +7,10 @@ use crate::{ItemResponse, User, UserToken};
 use super::AkahuClient;
 use reqwest::Method;
 
+#[allow(
+    clippy::same_name_method,
+    reason = "AkahuApi mirrors these methods so callers need not import the trait"
+)]
 impl AkahuClient {
     /// Get the authenticated user's profile information.
     ///
@@ -0,0 +1,177 @@
+//! Request metrics emitted through the [`metrics`] facade.
+//!
+//! Enabled by the `metrics` feature. Two metrics are recorded for every request:
+//!
+//! - `akahu_requests_total{method, endpoint, status}` - a counter of completed
+//!   requests. `status` is the HTTP status code, or `error` if no response was
+//!   received.
+//! - `akahu_request_duration_seconds{method, endpoint}` - a histogram of request
+//!   latency.
+//!
+//! The `endpoint` label is the request path relative to the base URL with any
+//! identifiers replaced by `{id}` (e.g. `accounts/{id}`), so its cardinality is
+//! bounded by the number of endpoints rather than the number of resources.
+
+use std::time::Instant;
+
+/// Name of the request counter.
+const REQUESTS_TOTAL: &str = "akahu_requests_total";
+/// Name of the request latency histogram.
+const REQUEST_DURATION_SECONDS: &str = "akahu_request_duration_seconds";
+
+/// Measures a single request from creation until [`finish`](Self::finish).
+pub(super) struct RequestTimer {
+    /// HTTP method of the request
+    method: String,
+    /// Low-cardinality endpoint label
+    endpoint: String,
+    /// When the request was started
+    started: Instant,
+}
+
+impl RequestTimer {
+    /// Start timing a request.
+    pub(super) fn start(base_url: &str, req: &reqwest::Request) -> Self {
+        Self {
+            method: req.method().to_string(),
+            endpoint: endpoint_label(base_url, req.url()),
+            started: Instant::now(),
+        }
+    }
+
+    /// Record the outcome of the request.
+    pub(super) fn finish(self, status: Option<reqwest::StatusCode>) {
+        let status =
+            status.map_or_else(|| "error".to_string(), |status| status.as_u16().to_string());
+
+        metrics::counter!(
+            REQUESTS_TOTAL,
+            "method" => self.method.clone(),
+            "endpoint" => self.endpoint.clone(),
+            "status" => status
+        )
+        .increment(1);
+        metrics::histogram!(
+            REQUEST_DURATION_SECONDS,
+            "method" => self.method,
+            "endpoint" => self.endpoint
+        )
+        .record(self.started.elapsed().as_secs_f64());
+    }
+}
+
+/// Build the endpoint label for a request URL.
+///
+/// Akahu identifiers always contain an underscore (`acc_...`, `trans_...`,
+/// `_merchant...`), while endpoint path segments never do, so any segment with an
+/// underscore or digit is treated as an identifier.
+fn endpoint_label(base_url: &str, url: &reqwest::Url) -> String {
+    let base_path = reqwest::Url::parse(base_url)
+        .map(|base| base.path().trim_end_matches('/').to_string())
+        .unwrap_or_default();
+    let path = url.path();
+    let path = path.strip_prefix(base_path.as_str()).unwrap_or(path);
+
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            if segment.contains(|c: char| c == '_' || c.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountId,
+        test_utils::{account_json, mock_client, user_token},
+    };
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    #[test]
+    fn test_endpoint_label_strips_ids() {
+        let url =
+            reqwest::Url::parse("https://api.akahu.io/v1/accounts/acc_123/transactions").unwrap();
+
+        assert_eq!(
+            endpoint_label("https://api.akahu.io/v1", &url),
+            "accounts/{id}/transactions"
+        );
+    }
+
+    #[test]
+    fn test_request_counter_increments() {
+        let server_runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = server_runtime.block_on(async {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/accounts/acc_123"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "item": account_json("acc_123")
+                })))
+                .mount(&server)
+                .await;
+            server
+        });
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let client = mock_client(&server);
+        let account_id = AccountId::new("acc_123").unwrap();
+
+        // A current-thread runtime keeps the request on this thread, where the
+        // local recorder is installed.
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        metrics::with_local_recorder(&recorder, || {
+            runtime
+                .block_on(client.get_account(&user_token(), &account_id))
+                .unwrap();
+        });
+
+        let snapshot = snapshotter.snapshot().into_vec();
+        let counter = snapshot
+            .iter()
+            .find(|(key, _, _, _)| key.key().name() == REQUESTS_TOTAL)
+            .unwrap();
+
+        let labels: Vec<_> = counter
+            .0
+            .key()
+            .labels()
+            .map(|label| (label.key(), label.value()))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                ("method", "GET"),
+                ("endpoint", "accounts/{id}"),
+                ("status", "200")
+            ]
+        );
+        assert_eq!(counter.3, DebugValue::Counter(1));
+        assert!(
+            snapshot
+                .iter()
+                .any(|(key, _, _, _)| key.key().name() == REQUEST_DURATION_SECONDS),
+            "latency should be recorded"
+        );
+    }
+}
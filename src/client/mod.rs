@@ -1,12 +1,31 @@
 //! Akahu API client implementation.
 
 mod accounts;
+mod api;
+#[cfg(feature = "blocking")]
+mod blocking;
+mod cache;
+mod categories;
 mod core;
+mod dashboard;
 mod me;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod oauth;
+mod observer;
+mod parties;
+mod rate_limit;
 mod refresh;
 mod transactions;
 
-use crate::{AppSecret, AppToken};
+use crate::{AppSecret, AppToken, Scope};
+
+pub use api::AkahuApi;
+#[cfg(feature = "blocking")]
+pub use blocking::AkahuClientBlocking;
+pub use cache::ResponseCache;
+pub use observer::Observer;
+pub use transactions::TransactionPages;
 
 /// Default base URL for the Akahu API
 const DEFAULT_BASE_URL: &str = "https://api.akahu.io/v1";
@@ -14,6 +33,10 @@ const DEFAULT_BASE_URL: &str = "https://api.akahu.io/v1";
 /// The main Akahu API client.
 ///
 /// Use the builder pattern to construct a new client.
+///
+/// Clones share the underlying connection pool, response cache, observer and
+/// rate limit, so a client can be cloned freely into concurrent tasks.
+#[derive(Clone)]
 pub struct AkahuClient {
     /// HTTP client for making requests
     client: reqwest::Client,
@@ -23,6 +46,14 @@ pub struct AkahuClient {
     app_secret: Option<AppSecret>,
     /// Base URL for API requests
     base_url: String,
+    /// Scopes the app is registered for, used to validate authorization URLs
+    declared_scopes: Option<Vec<Scope>>,
+    /// Optional cache for conditional `GET` requests
+    response_cache: Option<ResponseCache>,
+    /// Optional hooks called around every request
+    observer: Option<std::sync::Arc<dyn Observer>>,
+    /// Optional limit on how quickly requests are sent
+    rate_limiter: Option<rate_limit::RateLimiter>,
 }
 
 impl AkahuClient {
@@ -33,21 +64,66 @@ impl AkahuClient {
     /// * `client` - The HTTP client to use for requests
     /// * `app_id_token` - Your Akahu application ID token
     /// * `base_url` - Optional custom base URL (defaults to `https://api.akahu.io/v1`)
+    ///
+    /// The base URL may include a path, e.g. `https://gw.internal/akahu/v1`, in
+    /// which case endpoints are resolved beneath it. A trailing `/` is ignored.
+    ///
+    /// The app token is not checked here, so an empty or malformed token only
+    /// surfaces as an error once a request is made. Use [`try_new`](Self::try_new)
+    /// to validate it up front.
     pub fn new<T: Into<AppToken>>(
         client: reqwest::Client,
         app_id_token: T,
         base_url: Option<String>,
     ) -> Self {
-        let base_url = base_url.unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let mut base_url = base_url.unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        base_url.truncate(base_url.trim_end_matches('/').len());
 
         Self {
             client,
             app_id_token: app_id_token.into(),
             app_secret: None,
             base_url,
+            declared_scopes: None,
+            response_cache: None,
+            observer: None,
+            rate_limiter: None,
         }
     }
 
+    /// Create a new Akahu client, checking the app token can be sent with requests.
+    ///
+    /// Takes the same arguments as [`new`](Self::new). Akahu rejects requests with
+    /// a missing `X-Akahu-Id` header as a generic 403, so this catches an empty
+    /// token, or one with characters that are invalid in an HTTP header, when the
+    /// client is built instead.
+    ///
+    /// # Returns
+    ///
+    /// The client, or [`AkahuError::Validation`](crate::AkahuError::Validation) if
+    /// the app token is empty or not a valid header value.
+    pub fn try_new<T: Into<AppToken>>(
+        client: reqwest::Client,
+        app_id_token: T,
+        base_url: Option<String>,
+    ) -> crate::error::AkahuResult<Self> {
+        let app_id_token = app_id_token.into();
+
+        if app_id_token.trim().is_empty() {
+            return Err(crate::error::AkahuError::Validation {
+                message: "app token must not be empty".to_string(),
+            });
+        }
+        if reqwest::header::HeaderValue::from_str(&app_id_token).is_err() {
+            return Err(crate::error::AkahuError::Validation {
+                message: "app token contains characters that are not valid in an HTTP header"
+                    .to_string(),
+            });
+        }
+
+        Ok(Self::new(client, app_id_token, base_url))
+    }
+
     /// Set the app secret for app-scoped endpoints.
     ///
     /// The app secret is required for app-scoped endpoints like Categories.
@@ -56,4 +132,126 @@ impl AkahuClient {
         self.app_secret = Some(app_secret.into());
         self
     }
+
+    /// Set the scopes your app was registered with.
+    ///
+    /// When set, [`authorization_url`](Self::authorization_url) rejects requests
+    /// for any other scope, rather than sending the user to an OAuth error.
+    pub fn with_declared_scopes<I: IntoIterator<Item = Scope>>(mut self, scopes: I) -> Self {
+        self.declared_scopes = Some(scopes.into_iter().collect());
+        self
+    }
+
+    /// Cache `GET` responses that carry an `ETag`, revalidating them with
+    /// `If-None-Match` on later requests.
+    ///
+    /// See [`ResponseCache`] for details. Pass a clone of the same cache to share
+    /// it between clients.
+    pub fn with_response_cache(mut self, cache: ResponseCache) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// Call `observer` before and after every request.
+    ///
+    /// See [`Observer`] for the callbacks available.
+    pub fn with_observer(mut self, observer: std::sync::Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Send at most `requests_per_second` requests each second.
+    ///
+    /// Requests over the limit wait until they can be sent rather than failing,
+    /// so bulk syncs stay under Akahu's rate limit instead of reacting to `429`
+    /// responses. Bursts of up to `requests_per_second` requests are sent
+    /// immediately. Clones of this client share the same limit.
+    pub fn with_rate_limit(mut self, requests_per_second: std::num::NonZeroU32) -> Self {
+        self.rate_limiter = Some(rate_limit::RateLimiter::new(requests_per_second));
+        self
+    }
+
+    /// Append a path prefix to the base URL.
+    ///
+    /// Useful when Akahu is proxied behind a gateway that mounts the API under a
+    /// sub-path, e.g. a base URL of `https://gw.internal` with a base path of
+    /// `akahu/v1` sends requests to `https://gw.internal/akahu/v1/accounts`.
+    pub fn with_base_path<T: AsRef<str>>(mut self, base_path: T) -> Self {
+        let base_path = base_path.as_ref().trim_matches('/');
+        if !base_path.is_empty() {
+            self.base_url = format!("{}/{}", self.base_url, base_path);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{APP_TOKEN, account_json, user_token};
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    async fn mount_accounts(server: &MockServer, accounts_path: &str, calls: u64) {
+        Mock::given(method("GET"))
+            .and(path(accounts_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [account_json("acc_123")]
+            })))
+            .expect(calls)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_base_url_with_sub_path() {
+        let server = MockServer::start().await;
+        mount_accounts(&server, "/akahu/v1/accounts", 2).await;
+
+        for base_url in [
+            format!("{}/akahu/v1", server.uri()),
+            format!("{}/akahu/v1/", server.uri()),
+        ] {
+            let client = AkahuClient::new(reqwest::Client::new(), APP_TOKEN, Some(base_url));
+            client.get_accounts(&user_token()).await.unwrap();
+        }
+    }
+
+    #[test]
+    fn test_try_new_validates_app_token() {
+        for token in ["", "   "] {
+            let result = AkahuClient::try_new(reqwest::Client::new(), token, None);
+            assert!(
+                matches!(&result, Err(crate::AkahuError::Validation { message }) if message.contains("empty")),
+                "expected an empty token to be rejected"
+            );
+        }
+
+        let result = AkahuClient::try_new(reqwest::Client::new(), "app_token\n123", None);
+        assert!(
+            matches!(&result, Err(crate::AkahuError::Validation { message }) if message.contains("header")),
+            "expected a newline to be rejected"
+        );
+
+        AkahuClient::try_new(reqwest::Client::new(), APP_TOKEN, None).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_with_base_path() {
+        let server = MockServer::start().await;
+        mount_accounts(&server, "/akahu/v1/accounts", 1).await;
+
+        let client = AkahuClient::new(reqwest::Client::new(), APP_TOKEN, Some(server.uri()))
+            .with_base_path("/akahu/v1/");
+        let accounts = client.get_accounts(&user_token()).await.unwrap();
+
+        assert_eq!(accounts.items.len(), 1, "expected a single account");
+    }
 }
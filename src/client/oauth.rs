@@ -0,0 +1,290 @@
+//! OAuth endpoint implementations.
+//!
+//! This module contains methods for starting the OAuth flow and completing it by
+//! exchanging an authorization code for a user access token.
+
+use crate::{
+    AuthCode, AuthorizedSession, ClientSecret, RedirectUri, Scope,
+    models::{TokenExchangeRequest, TokenExchangeResponse},
+};
+
+use super::AkahuClient;
+use reqwest::Method;
+
+/// Akahu's OAuth authorization page
+const AUTHORIZATION_URL: &str = "https://oauth.akahu.nz";
+
+impl AkahuClient {
+    /// Build the URL to send a user to in order to authorize your app.
+    ///
+    /// The redirect URI must be an absolute `https` URL (or `http` on localhost),
+    /// as checked by [`RedirectUri::parse`]. If
+    /// [declared scopes](Self::with_declared_scopes) are configured, every
+    /// requested scope must be among them.
+    ///
+    /// # Arguments
+    ///
+    /// * `redirect_uri` - Where Akahu should send the user afterwards; must match your app settings
+    /// * `scopes` - The scopes to request
+    /// * `state` - Optional value echoed back on redirect, used to prevent CSRF
+    ///
+    /// # Returns
+    ///
+    /// The authorization URL, [`AkahuError::InvalidRedirectUri`](crate::AkahuError::InvalidRedirectUri)
+    /// if the redirect URI is not valid, or
+    /// [`AkahuError::Validation`](crate::AkahuError::Validation) if a requested
+    /// scope was not declared.
+    ///
+    /// [<https://developers.akahu.nz/docs/authorizing-with-oauth2#the-authorization-request>]
+    pub fn authorization_url(
+        &self,
+        redirect_uri: &RedirectUri,
+        scopes: &[Scope],
+        state: Option<&str>,
+    ) -> crate::error::AkahuResult<reqwest::Url> {
+        let redirect_uri = RedirectUri::parse(redirect_uri.as_str())?;
+
+        if let Some(declared) = &self.declared_scopes {
+            let undeclared: Vec<&str> = scopes
+                .iter()
+                .filter(|scope| !declared.contains(scope))
+                .map(Scope::as_str)
+                .collect();
+            if !undeclared.is_empty() {
+                return Err(crate::error::AkahuError::Validation {
+                    message: format!(
+                        "scopes not declared for this app: {}",
+                        undeclared.join(", ")
+                    ),
+                });
+            }
+        }
+
+        let scope = scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut url = reqwest::Url::parse(AUTHORIZATION_URL)?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.app_id_token)
+            .append_pair("redirect_uri", redirect_uri.as_str())
+            .append_pair("scope", &scope);
+        if let Some(state) = state {
+            url.query_pairs_mut().append_pair("state", state);
+        }
+        Ok(url)
+    }
+
+    /// Exchange an authorization code for a user session.
+    ///
+    /// The authorization code is only valid for around 60 seconds, so this should
+    /// be called as soon as the user is redirected back to your application.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The authorization code from the OAuth redirect
+    /// * `redirect_uri` - The redirect URI used to start the OAuth flow
+    /// * `client_secret` - Your app secret
+    ///
+    /// # Returns
+    ///
+    /// A session holding the user's access token and the scopes it was granted.
+    /// Use [`exchange_authorization_code_raw`](Self::exchange_authorization_code_raw)
+    /// for the unprocessed response.
+    ///
+    /// [<https://developers.akahu.nz/docs/authorizing-with-oauth2#exchanging-the-authorization-code>]
+    pub async fn exchange_authorization_code(
+        &self,
+        code: &AuthCode,
+        redirect_uri: &RedirectUri,
+        client_secret: &ClientSecret,
+    ) -> crate::error::AkahuResult<AuthorizedSession> {
+        let response = self
+            .exchange_authorization_code_raw(code, redirect_uri, client_secret)
+            .await?;
+
+        Ok(AuthorizedSession::from_scope_string(
+            response.access_token,
+            &response.scope,
+        ))
+    }
+
+    /// Exchange an authorization code for a user access token, returning the
+    /// response as sent by Akahu.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The authorization code from the OAuth redirect
+    /// * `redirect_uri` - The redirect URI used to start the OAuth flow
+    /// * `client_secret` - Your app secret
+    ///
+    /// # Returns
+    ///
+    /// The token exchange response, including the access token and granted scopes.
+    ///
+    /// [<https://developers.akahu.nz/docs/authorizing-with-oauth2#exchanging-the-authorization-code>]
+    pub async fn exchange_authorization_code_raw(
+        &self,
+        code: &AuthCode,
+        redirect_uri: &RedirectUri,
+        client_secret: &ClientSecret,
+    ) -> crate::error::AkahuResult<TokenExchangeResponse> {
+        const URI: &str = "token";
+
+        let body = TokenExchangeRequest {
+            grant_type: "authorization_code",
+            code: code.clone(),
+            redirect_uri: redirect_uri.clone(),
+            client_id: self.app_id_token.clone(),
+            client_secret: client_secret.clone(),
+        };
+
+        let req = self
+            .client
+            .request(Method::POST, format!("{}/{}", self.base_url, URI))
+            .json(&body)
+            .build()?;
+
+        self.execute_request(req).await
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use crate::{
+        AkahuClient, AuthCode, ClientSecret, RedirectUri, Scope,
+        error::AkahuError,
+        test_utils::{APP_TOKEN, mock_client},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_json, method, path},
+    };
+
+    async fn exchange(server: &MockServer) -> crate::error::AkahuResult<crate::AuthorizedSession> {
+        mock_client(server)
+            .exchange_authorization_code(
+                &AuthCode::new("code_123"),
+                &RedirectUri::new("https://example.com/callback"),
+                &ClientSecret::new("secret_123"),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_exchange_authorization_code() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .and(body_json(serde_json::json!({
+                "grant_type": "authorization_code",
+                "code": "code_123",
+                "redirect_uri": "https://example.com/callback",
+                "client_id": APP_TOKEN,
+                "client_secret": "secret_123"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "access_token": "user_token_123",
+                "token_type": "bearer",
+                "scope": "ENDURING_CONSENT TRANSACTIONS"
+            })))
+            .mount(&server)
+            .await;
+
+        let session = exchange(&server).await.unwrap();
+
+        assert_eq!(session.token.as_str(), "user_token_123");
+        assert_eq!(
+            session.scopes,
+            [Scope::EnduringConsent, Scope::Transactions],
+            "scopes should match the response's scope string"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_authorization_code_oauth_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "invalid_grant",
+                "error_description": "Code has expired"
+            })))
+            .mount(&server)
+            .await;
+
+        let result = exchange(&server).await;
+
+        assert!(
+            matches!(&result, Err(AkahuError::OAuth { error, .. }) if error == "invalid_grant"),
+            "expected an OAuth error, got {result:?}"
+        );
+    }
+
+    fn client_with_declared_scopes() -> AkahuClient {
+        AkahuClient::new(reqwest::Client::new(), APP_TOKEN, None).with_declared_scopes([
+            Scope::EnduringConsent,
+            Scope::Accounts,
+            Scope::Transactions,
+        ])
+    }
+
+    #[test]
+    fn test_authorization_url_with_declared_scopes() {
+        let url = client_with_declared_scopes()
+            .authorization_url(
+                &RedirectUri::new("https://example.com/callback"),
+                &[Scope::EnduringConsent, Scope::Transactions],
+                Some("state_123"),
+            )
+            .unwrap();
+
+        assert_eq!(
+            url.as_str(),
+            "https://oauth.akahu.nz/?response_type=code&client_id=app_token_123\
+             &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback\
+             &scope=ENDURING_CONSENT+TRANSACTIONS&state=state_123"
+        );
+    }
+
+    #[test]
+    fn test_authorization_url_rejects_undeclared_scopes() {
+        let result = client_with_declared_scopes().authorization_url(
+            &RedirectUri::new("https://example.com/callback"),
+            &[Scope::EnduringConsent, Scope::Payments, Scope::Identity],
+            None,
+        );
+
+        assert!(
+            matches!(
+                &result,
+                Err(AkahuError::Validation { message }) if message.ends_with("PAYMENTS, IDENTITY")
+            ),
+            "expected a validation error, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_authorization_url_rejects_insecure_redirect_uri() {
+        let result = client_with_declared_scopes().authorization_url(
+            &RedirectUri::new("http://example.com/callback"),
+            &[Scope::EnduringConsent],
+            None,
+        );
+
+        assert!(
+            matches!(&result, Err(AkahuError::InvalidRedirectUri(_))),
+            "expected the redirect URI to be rejected, got {result:?}"
+        );
+    }
+}
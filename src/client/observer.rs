@@ -0,0 +1,83 @@
+//! Hooks for custom request instrumentation.
+
+use std::time::Duration;
+
+/// Callbacks invoked around every request the client sends.
+///
+/// Set with [`AkahuClient::with_observer`](crate::AkahuClient::with_observer) to
+/// feed your own logging or metrics backend without enabling the `metrics`
+/// feature. Both methods default to doing nothing, so implement only the ones
+/// you need.
+///
+/// Callbacks run inline on the request path and should return quickly.
+pub trait Observer: Send + Sync {
+    /// Called just before a request is sent, with its method and URL path.
+    fn on_request(&self, method: &reqwest::Method, path: &str) {
+        let _ = (method, path);
+    }
+
+    /// Called once the request completes, with the response status (or `None`
+    /// if no response was received) and how long the request took.
+    fn on_response(&self, status: Option<reqwest::StatusCode>, duration: Duration) {
+        let _ = (status, duration);
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::test_utils::{mock_client, user_token};
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    /// Records every callback as a string.
+    #[derive(Default)]
+    struct RecordingObserver {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl Observer for RecordingObserver {
+        fn on_request(&self, method: &reqwest::Method, path: &str) {
+            self.events.lock().unwrap().push(format!("{method} {path}"));
+        }
+
+        fn on_response(&self, status: Option<reqwest::StatusCode>, duration: Duration) {
+            assert!(duration > Duration::ZERO, "duration should be measured");
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{}", status.unwrap().as_u16()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_sees_request_and_response() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/accounts"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "success": false,
+                "message": "Unauthorized"
+            })))
+            .mount(&server)
+            .await;
+
+        let observer = Arc::new(RecordingObserver::default());
+        let client = mock_client(&server).with_observer(Arc::clone(&observer) as Arc<dyn Observer>);
+
+        client.get_accounts(&user_token()).await.unwrap_err();
+
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            vec!["GET /accounts".to_string(), "401".to_string()]
+        );
+    }
+}
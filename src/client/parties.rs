@@ -0,0 +1,120 @@
+//! Party endpoint implementations.
+//!
+//! This module contains methods for retrieving identity data about the parties
+//! who authorised access to the user's accounts.
+
+use crate::{AccountId, Party, UserToken};
+
+use super::AkahuClient;
+use reqwest::Method;
+
+#[allow(
+    clippy::same_name_method,
+    reason = "AkahuApi mirrors these methods so callers need not import the trait"
+)]
+impl AkahuClient {
+    /// Get the parties who authorised access to any of the user's accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    ///
+    /// # Returns
+    ///
+    /// A response containing party data from each connected institution. Access
+    /// the parties via the `.items` field.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_parties>]
+    pub async fn get_parties(
+        &self,
+        user_token: &UserToken,
+    ) -> crate::error::AkahuResult<crate::models::ListResponse<Party>> {
+        const URI: &str = "parties";
+
+        let headers = self.build_user_headers(user_token)?;
+
+        let req = self
+            .client
+            .request(Method::GET, format!("{}/{}", self.base_url, URI))
+            .headers(headers)
+            .build()?;
+
+        self.execute_request(req).await
+    }
+
+    /// Get the parties who authorised access to a specific account.
+    ///
+    /// Useful for per-account identity checks, where the top-level
+    /// [`get_parties`](Self::get_parties) would mix parties from every connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `account_id` - The unique identifier for the account (prefixed with `acc_`),
+    ///   or the [`Account`](crate::Account) itself
+    ///
+    /// # Returns
+    ///
+    /// A response containing the parties for the account. Access the parties via
+    /// the `.items` field.
+    pub async fn get_account_parties<Id: AsRef<AccountId> + ?Sized>(
+        &self,
+        user_token: &UserToken,
+        account_id: &Id,
+    ) -> crate::error::AkahuResult<crate::models::ListResponse<Party>> {
+        let uri = format!("accounts/{}/parties", account_id.as_ref().as_str());
+
+        let headers = self.build_user_headers(user_token)?;
+
+        let req = self
+            .client
+            .request(Method::GET, format!("{}/{}", self.base_url, uri))
+            .headers(headers)
+            .build()?;
+
+        self.execute_request(req).await
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use crate::{
+        AccountId,
+        test_utils::{mock_client, user_token},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path},
+    };
+
+    #[tokio::test]
+    async fn test_get_account_parties() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/accounts/acc_123/parties"))
+            .and(header("Authorization", "Bearer user_token_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [{
+                    "_id": "party_123",
+                    "name": "John Smith",
+                    "email": "john@example.com"
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let parties = mock_client(&server)
+            .get_account_parties(&user_token(), &AccountId::new("acc_123").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(parties.items.len(), 1, "expected a single party");
+        assert_eq!(parties.items.first().unwrap().name, "John Smith");
+    }
+}
@@ -0,0 +1,154 @@
+//! Proactive client-side rate limiting.
+
+use std::{
+    num::NonZeroU32,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+/// Spaces out requests to stay under a fixed rate.
+///
+/// This is a token bucket holding up to one second's worth of requests,
+/// implemented as the equivalent generic cell rate algorithm: rather than
+/// counting tokens, it tracks the time at which the bucket would next be empty.
+/// Each request reserves its slot up front, so concurrent callers are served in
+/// the order they arrive. Clones share the same bucket.
+#[derive(Debug, Clone)]
+pub(super) struct RateLimiter {
+    /// Time between requests at the sustained rate.
+    interval: Duration,
+    /// How far ahead of the sustained rate a burst may run.
+    burst_tolerance: Duration,
+    /// When the bucket will next be empty if no further requests are made.
+    next_empty: Arc<Mutex<Option<Instant>>>,
+}
+
+impl RateLimiter {
+    /// Allow up to `requests_per_second` requests each second, in bursts of at
+    /// most that many.
+    pub(super) fn new(requests_per_second: NonZeroU32) -> Self {
+        let interval = Duration::from_secs(1)
+            .checked_div(requests_per_second.get())
+            .unwrap_or_default();
+        let burst_tolerance = interval
+            .checked_mul(requests_per_second.get().saturating_sub(1))
+            .unwrap_or_default();
+
+        Self {
+            interval,
+            burst_tolerance,
+            next_empty: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Wait until a request may be sent, reserving its slot.
+    pub(super) async fn acquire(&self) {
+        let wait = {
+            let mut next_empty = self
+                .next_empty
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+            let now = Instant::now();
+            let start = next_empty.map_or(now, |next_empty| next_empty.max(now));
+            let allowed_at = start.checked_sub(self.burst_tolerance).unwrap_or(now);
+            *next_empty = Some(start.checked_add(self.interval).unwrap_or(start));
+            drop(next_empty);
+
+            allowed_at.saturating_duration_since(now)
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::{AkahuClient, test_utils::APP_TOKEN};
+
+    #[tokio::test(start_paused = true)]
+    async fn test_requests_are_spaced_out() {
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap());
+        let start = Instant::now();
+
+        let mut elapsed = Vec::new();
+        for _ in 0..5 {
+            limiter.acquire().await;
+            elapsed.push(start.elapsed());
+        }
+
+        assert_eq!(
+            elapsed,
+            [0, 0, 500, 1000, 1500].map(Duration::from_millis),
+            "a burst of two, then one request every 500ms"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_bucket_refills_when_idle() {
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap());
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let start = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        assert_eq!(
+            start.elapsed(),
+            Duration::ZERO,
+            "a full burst is available again"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_clones_share_bucket() {
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap());
+        let clone = limiter.clone();
+        let start = Instant::now();
+
+        let mut elapsed = Vec::new();
+        for limiter in [&limiter, &clone, &limiter, &clone] {
+            limiter.acquire().await;
+            elapsed.push(start.elapsed());
+        }
+
+        assert_eq!(
+            elapsed,
+            [0, 0, 500, 1000].map(Duration::from_millis),
+            "requests through either clone draw from the same bucket"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_client_clones_share_rate_limit() {
+        let client = AkahuClient::new(reqwest::Client::new(), APP_TOKEN, None)
+            .with_rate_limit(NonZeroU32::new(2).unwrap());
+        let clone = client.clone();
+        let limiters =
+            [&client, &clone, &client, &clone].map(|client| client.rate_limiter.clone().unwrap());
+        let start = Instant::now();
+
+        let mut elapsed = Vec::new();
+        for limiter in &limiters {
+            limiter.acquire().await;
+            elapsed.push(start.elapsed());
+        }
+
+        assert_eq!(
+            elapsed,
+            [0, 0, 500, 1000].map(Duration::from_millis),
+            "a cloned client should not get a fresh bucket"
+        );
+    }
+}
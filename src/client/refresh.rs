@@ -7,6 +7,10 @@ use crate::UserToken;
 use super::AkahuClient;
 use reqwest::Method;
 
+#[allow(
+    clippy::same_name_method,
+    reason = "AkahuApi mirrors these methods so callers need not import the trait"
+)]
 impl AkahuClient {
     /// Refresh all accounts connected to your application.
     ///
@@ -39,7 +43,7 @@ impl AkahuClient {
             .headers(headers)
             .build()?;
 
-        let res = self.client.execute(req).await?;
+        let res = self.send_request(req).await?;
 
         if res.status().is_success() {
             Ok(())
@@ -87,7 +91,7 @@ impl AkahuClient {
             .headers(headers)
             .build()?;
 
-        let res = self.client.execute(req).await?;
+        let res = self.send_request(req).await?;
 
         if res.status().is_success() {
             Ok(())
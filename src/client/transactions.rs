@@ -2,12 +2,18 @@
 //!
 //! This module contains methods for retrieving settled and pending transactions.
 
-use crate::{AccountId, Cursor, PaginatedResponse, PendingTransaction, Transaction, UserToken};
+use crate::{
+    AccountId, Cursor, PaginatedResponse, PaginationCursor, PendingTransaction, Transaction,
+    TransactionId, TransactionPage, TransactionQueryParams, UserToken,
+};
 
-use super::AkahuClient;
+use super::{AkahuClient, core::build_url};
 use reqwest::Method;
-use std::collections::HashMap;
 
+#[allow(
+    clippy::same_name_method,
+    reason = "AkahuApi mirrors these methods so callers need not import the trait"
+)]
 impl AkahuClient {
     /// Get a list of the user's settled transactions within a specified time range.
     ///
@@ -46,28 +52,9 @@ impl AkahuClient {
 
         let headers = self.build_user_headers(user_token)?;
 
-        let mut query_params = HashMap::new();
+        let query = TransactionQueryParams { start, end };
 
-        if let Some(start) = start {
-            query_params.insert(
-                "start",
-                start.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
-            );
-        }
-
-        if let Some(end) = end {
-            query_params.insert(
-                "end",
-                end.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
-            );
-        }
-
-        if let Some(cursor) = cursor {
-            query_params.insert("cursor", cursor.to_string());
-        }
-
-        let url =
-            reqwest::Url::parse_with_params(&format!("{}/{}", self.base_url, URI), &query_params)?;
+        let url = build_url(&self.base_url, URI, &(query, cursor))?;
 
         let req = self
             .client
@@ -78,6 +65,158 @@ impl AkahuClient {
         self.execute_request(req).await
     }
 
+    /// Get the first page of the user's settled transactions for a query.
+    ///
+    /// Unlike [`get_transactions`](Self::get_transactions), the returned page keeps
+    /// track of the query it was fetched with, so the following page can only be
+    /// requested with the same bounds via
+    /// [`get_next_transaction_page`](Self::get_next_transaction_page).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `query` - The date range to fetch transactions for
+    ///
+    /// # Returns
+    ///
+    /// The first page of transactions.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_transactions>]
+    pub async fn get_transaction_page(
+        &self,
+        user_token: &UserToken,
+        query: &TransactionQueryParams,
+    ) -> crate::error::AkahuResult<TransactionPage> {
+        let page = self
+            .get_transactions(user_token, query.start, query.end, None)
+            .await?;
+
+        Ok(TransactionPage::new(
+            page.items,
+            query.clone(),
+            page.cursor.next,
+        ))
+    }
+
+    /// Get the page of settled transactions following `page`.
+    ///
+    /// The request reuses the bounds `page` was fetched with, so a cursor can never
+    /// be combined with a different query.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `page` - The previous page
+    ///
+    /// # Returns
+    ///
+    /// The next page, or `None` if `page` was the last one.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_transactions>]
+    pub async fn get_next_transaction_page(
+        &self,
+        user_token: &UserToken,
+        page: TransactionPage,
+    ) -> crate::error::AkahuResult<Option<TransactionPage>> {
+        let (query, cursor) = page.into_next();
+        let Some(cursor) = cursor else {
+            return Ok(None);
+        };
+
+        let page = self
+            .get_transactions(user_token, query.start, query.end, Some(cursor))
+            .await?;
+
+        Ok(Some(TransactionPage::new(
+            page.items,
+            query,
+            page.cursor.next,
+        )))
+    }
+
+    /// Resume paginating settled transactions from a persisted cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `cursor` - A cursor from [`TransactionPage::resume_cursor`]
+    ///
+    /// # Returns
+    ///
+    /// The page the cursor points to, fetched with the cursor's original query.
+    /// Fails without making a request if the cursor belongs to another endpoint.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_transactions>]
+    pub async fn resume_transaction_page(
+        &self,
+        user_token: &UserToken,
+        cursor: PaginationCursor,
+    ) -> crate::error::AkahuResult<TransactionPage> {
+        let (query, cursor) = cursor.into_parts_for(PaginationCursor::TRANSACTIONS_ENDPOINT)?;
+
+        let page = self
+            .get_transactions(user_token, query.start, query.end, Some(cursor))
+            .await?;
+
+        Ok(TransactionPage::new(page.items, query, page.cursor.next))
+    }
+
+    /// Page through settled transactions in a time range.
+    ///
+    /// Unlike [`get_all_transactions`](Self::get_all_transactions), pages are
+    /// handed back one at a time, so each page's
+    /// [`resume_cursor`](TransactionPage::resume_cursor) can be persisted as a
+    /// checkpoint before moving on.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `query` - The time range to fetch
+    ///
+    /// # Returns
+    ///
+    /// A [`TransactionPages`] that fetches the first page on its first
+    /// [`next_page`](TransactionPages::next_page) call.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_transactions>]
+    pub const fn transaction_pages<'a>(
+        &'a self,
+        user_token: &'a UserToken,
+        query: TransactionQueryParams,
+    ) -> TransactionPages<'a> {
+        TransactionPages {
+            client: self,
+            user_token,
+            next: Some(PageRequest::First(query)),
+            fetched: 0,
+        }
+    }
+
+    /// Page through settled transactions starting from a persisted checkpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `cursor` - A cursor from [`TransactionPage::resume_cursor`]
+    ///
+    /// # Returns
+    ///
+    /// A [`TransactionPages`] that fetches the page `cursor` points to first.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_transactions>]
+    pub const fn resume_transaction_pages<'a>(
+        &'a self,
+        user_token: &'a UserToken,
+        cursor: PaginationCursor,
+    ) -> TransactionPages<'a> {
+        TransactionPages {
+            client: self,
+            user_token,
+            next: Some(PageRequest::Resume(cursor)),
+            fetched: 0,
+        }
+    }
+
     /// Get a list of the user's pending transactions.
     ///
     /// This endpoint returns pending transactions for all accounts that the user has connected
@@ -139,7 +278,8 @@ impl AkahuClient {
     /// # Arguments
     ///
     /// * `user_token` - The user's access token obtained through OAuth
-    /// * `account_id` - The unique identifier for the account (prefixed with `acc_`)
+    /// * `account_id` - The unique identifier for the account (prefixed with `acc_`),
+    ///   or the [`Account`](crate::Account) itself
     /// * `query` - Optional query parameters to filter by date range and paginate
     ///
     /// # Returns
@@ -147,40 +287,21 @@ impl AkahuClient {
     /// A paginated response containing transactions and a cursor for fetching more pages.
     ///
     /// [<https://developers.akahu.nz/reference/get_accounts-id-transactions>]
-    pub async fn get_account_transactions(
+    pub async fn get_account_transactions<Id: AsRef<AccountId> + ?Sized>(
         &self,
         user_token: &UserToken,
-        account_id: &AccountId,
+        account_id: &Id,
         start: Option<chrono::DateTime<chrono::Utc>>,
         end: Option<chrono::DateTime<chrono::Utc>>,
         cursor: Option<Cursor>,
     ) -> crate::error::AkahuResult<PaginatedResponse<Transaction>> {
-        let uri = format!("accounts/{}/transactions", account_id.as_str());
+        let uri = format!("accounts/{}/transactions", account_id.as_ref().as_str());
 
         let headers = self.build_user_headers(user_token)?;
 
-        let mut query_params = HashMap::new();
-
-        if let Some(start) = start {
-            query_params.insert(
-                "start",
-                start.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
-            );
-        }
+        let query = TransactionQueryParams { start, end };
 
-        if let Some(end) = end {
-            query_params.insert(
-                "end",
-                end.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
-            );
-        }
-
-        if let Some(cursor) = cursor {
-            query_params.insert("cursor", cursor.to_string());
-        }
-
-        let url =
-            reqwest::Url::parse_with_params(&format!("{}/{}", self.base_url, uri), &query_params)?;
+        let url = build_url(&self.base_url, &uri, &(query, cursor))?;
 
         let req = self
             .client
@@ -209,19 +330,23 @@ impl AkahuClient {
     /// # Arguments
     ///
     /// * `user_token` - The user's access token obtained through OAuth
-    /// * `account_id` - The unique identifier for the account (prefixed with `acc_`)
+    /// * `account_id` - The unique identifier for the account (prefixed with `acc_`),
+    ///   or the [`Account`](crate::Account) itself
     ///
     /// # Returns
     ///
     /// A vector containing all pending transactions for the account.
     ///
     /// [<https://developers.akahu.nz/reference/get_accounts-id-transactions-pending>]
-    pub async fn get_account_pending_transactions(
+    pub async fn get_account_pending_transactions<Id: AsRef<AccountId> + ?Sized>(
         &self,
         user_token: &UserToken,
-        account_id: &AccountId,
+        account_id: &Id,
     ) -> crate::error::AkahuResult<Vec<PendingTransaction>> {
-        let uri = format!("accounts/{}/transactions/pending", account_id.as_str());
+        let uri = format!(
+            "accounts/{}/transactions/pending",
+            account_id.as_ref().as_str()
+        );
 
         let headers = self.build_user_headers(user_token)?;
 
@@ -236,4 +361,1061 @@ impl AkahuClient {
 
         Ok(response.items)
     }
+
+    /// Get a single settled transaction by its ID.
+    ///
+    /// This is useful for resolving a transaction ID (e.g. from a webhook event) to
+    /// the full, enriched transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `transaction_id` - The unique identifier for the transaction (prefixed with `trans_`)
+    ///
+    /// # Returns
+    ///
+    /// The requested transaction.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_transactions-id>]
+    pub async fn get_transaction(
+        &self,
+        user_token: &UserToken,
+        transaction_id: &TransactionId,
+    ) -> crate::error::AkahuResult<Transaction> {
+        let uri = format!("transactions/{}", transaction_id.as_str());
+
+        let headers = self.build_user_headers(user_token)?;
+
+        let req = self
+            .client
+            .request(Method::GET, format!("{}/{}", self.base_url, uri))
+            .headers(headers)
+            .build()?;
+
+        let response: crate::models::ItemResponse<Transaction> = self.execute_request(req).await?;
+
+        Ok(response.item)
+    }
+
+    /// Get multiple settled transactions by their IDs in a single request.
+    ///
+    /// This is more efficient than calling [`get_transaction`](Self::get_transaction)
+    /// repeatedly, e.g. when a webhook delivers a batch of new transaction IDs.
+    /// Transactions that cannot be found are omitted from the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `ids` - The unique identifiers of the transactions to fetch
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the matching transactions.
+    ///
+    /// [<https://developers.akahu.nz/reference/post_transactions-ids>]
+    pub async fn get_transactions_by_ids(
+        &self,
+        user_token: &UserToken,
+        ids: &[TransactionId],
+    ) -> crate::error::AkahuResult<Vec<Transaction>> {
+        const URI: &str = "transactions/ids";
+
+        let headers = self.build_user_headers(user_token)?;
+
+        let req = self
+            .client
+            .request(Method::POST, format!("{}/{}", self.base_url, URI))
+            .headers(headers)
+            .json(ids)
+            .build()?;
+
+        let response: crate::models::ListResponse<Transaction> = self.execute_request(req).await?;
+
+        Ok(response.items)
+    }
+
+    /// Fetch every settled transaction dated after `since`, for incremental syncing.
+    ///
+    /// This queries [`get_all_transactions`](Self::get_all_transactions) with
+    /// `start = since` and `end = now`.
+    ///
+    /// Alongside the transactions, a new watermark is returned: the latest `date`
+    /// seen, or `now` if no transactions were returned. Pass the watermark as `since`
+    /// on the next sync. Because `start` is exclusive and `end` is inclusive, each
+    /// transaction is returned by exactly one sync, provided it was available from
+    /// Akahu by the time the sync that covers its date ran.
+    ///
+    /// **Note:** NZ banks often only provide dates accurate to the day, and may
+    /// deliver transactions late. Transactions posted with a date at or before the
+    /// watermark after a sync has run will not be picked up by later syncs.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `since` - The watermark returned by the previous sync (exclusive)
+    ///
+    /// # Returns
+    ///
+    /// All transactions in the range, and the watermark to use for the next sync.
+    pub async fn sync_transactions_since(
+        &self,
+        user_token: &UserToken,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> crate::error::AkahuResult<(Vec<Transaction>, chrono::DateTime<chrono::Utc>)> {
+        let now = chrono::Utc::now();
+
+        let transactions = self
+            .get_all_transactions(
+                user_token,
+                &TransactionQueryParams::new().start(since).end(now),
+            )
+            .await?;
+
+        let watermark = transactions
+            .iter()
+            .map(|transaction| transaction.date)
+            .max()
+            .unwrap_or(now);
+
+        Ok((transactions, watermark))
+    }
+
+    /// Fetch every settled transaction in a time range, following the pagination
+    /// cursor of [`get_transactions`](Self::get_transactions) until all pages have
+    /// been fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `query` - The date range to fetch transactions for
+    ///
+    /// # Returns
+    ///
+    /// All transactions in the range.
+    pub async fn get_all_transactions(
+        &self,
+        user_token: &UserToken,
+        query: &TransactionQueryParams,
+    ) -> crate::error::AkahuResult<Vec<Transaction>> {
+        self.get_all_transactions_with_progress(user_token, query, |_, _, _| {})
+            .await
+    }
+
+    /// Like [`get_all_transactions`](Self::get_all_transactions), but reports
+    /// progress after each page is fetched.
+    ///
+    /// Akahu does not return a total count, so this is intended for progress
+    /// indicators that count pages rather than show a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `query` - The date range to fetch transactions for
+    /// * `on_page` - Called once per page with the zero-based page index, the
+    ///   number of transactions in that page, and whether more pages follow
+    ///
+    /// # Returns
+    ///
+    /// All transactions in the range.
+    pub async fn get_all_transactions_with_progress<F>(
+        &self,
+        user_token: &UserToken,
+        query: &TransactionQueryParams,
+        mut on_page: F,
+    ) -> crate::error::AkahuResult<Vec<Transaction>>
+    where
+        F: FnMut(usize, usize, bool),
+    {
+        let mut transactions = Vec::new();
+        let mut pages = self.transaction_pages(user_token, query.clone());
+
+        for page_index in 0_usize.. {
+            let Some(page) = pages.next_page().await? else {
+                break;
+            };
+            on_page(page_index, page.items.len(), page.has_more());
+            transactions.extend(page.items);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Estimate how many settled transactions a query will return, for progress
+    /// indicators.
+    ///
+    /// Akahu does not report a total count, so this fetches the first page. If it
+    /// is the only page, its length is the exact count. Otherwise the count is
+    /// extrapolated from how much of the query's date range the first page
+    /// covered, which assumes transactions are spread evenly over time.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `query` - The date range to estimate the transaction count for
+    ///
+    /// # Returns
+    ///
+    /// The estimated number of transactions, or `None` if there is more than one
+    /// page and no estimate is possible: the query has no `start`, or every
+    /// transaction in the first page has the same date.
+    ///
+    /// [<https://developers.akahu.nz/reference/get_transactions>]
+    pub async fn estimate_transaction_count(
+        &self,
+        user_token: &UserToken,
+        query: &TransactionQueryParams,
+    ) -> crate::error::AkahuResult<Option<usize>> {
+        let page = self
+            .get_transactions(user_token, query.start, query.end, None)
+            .await?;
+
+        if page.cursor.next.is_none() {
+            return Ok(Some(page.items.len()));
+        }
+
+        Ok(extrapolate_count(
+            &page.items,
+            query.start,
+            query.end.unwrap_or_else(chrono::Utc::now),
+        ))
+    }
+
+    /// Export every settled transaction in a time range as newline-delimited JSON.
+    ///
+    /// Pages are fetched one at a time and written to `writer` as they arrive, one
+    /// transaction per line, so large exports are never held in memory at once.
+    /// The writer is flushed once all pages have been written.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `query` - The date range to fetch transactions for
+    /// * `writer` - Destination for the NDJSON output
+    ///
+    /// # Returns
+    ///
+    /// The number of transactions written.
+    pub async fn export_transactions_ndjson<W>(
+        &self,
+        user_token: &UserToken,
+        query: &TransactionQueryParams,
+        mut writer: W,
+    ) -> crate::error::AkahuResult<usize>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let mut written = 0_usize;
+        let mut pages = self.transaction_pages(user_token, query.clone());
+
+        while let Some(page) = pages.next_page().await? {
+            let mut buffer = Vec::new();
+            for transaction in &page.items {
+                serde_json::to_writer(&mut buffer, transaction)
+                    .map_err(crate::error::AkahuError::JsonSerialization)?;
+                buffer.push(b'\n');
+            }
+            writer.write_all(&buffer).await?;
+            written = written.saturating_add(page.items.len());
+        }
+
+        writer.flush().await?;
+
+        Ok(written)
+    }
+
+    /// Export every settled transaction in a time range as a JSON array.
+    ///
+    /// Like [`export_transactions_ndjson`](Self::export_transactions_ndjson), each
+    /// page is written as soon as it arrives, but the output is a single JSON array
+    /// identical to serializing all the transactions at once. Use
+    /// [`write_transactions_json`](crate::write_transactions_json) for transactions
+    /// that have already been fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_token` - The user's access token obtained through OAuth
+    /// * `query` - The date range to fetch transactions for
+    /// * `writer` - Destination for the JSON output
+    /// * `pretty` - Whether to pretty-print the output
+    ///
+    /// # Returns
+    ///
+    /// The number of transactions written.
+    pub async fn export_transactions_json<W>(
+        &self,
+        user_token: &UserToken,
+        query: &TransactionQueryParams,
+        mut writer: W,
+        pretty: bool,
+    ) -> crate::error::AkahuResult<usize>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let mut encoder = crate::export::JsonArrayEncoder::new(pretty);
+        let mut buffer = Vec::new();
+        let mut pages = self.transaction_pages(user_token, query.clone());
+
+        while let Some(page) = pages.next_page().await? {
+            for transaction in &page.items {
+                encoder.push(&mut buffer, transaction)?;
+            }
+            writer.write_all(&buffer).await?;
+            buffer.clear();
+        }
+
+        encoder.finish(&mut buffer);
+        writer.write_all(&buffer).await?;
+        writer.flush().await?;
+
+        Ok(encoder.written())
+    }
+}
+
+/// Extrapolate the number of transactions between `start` and `end` from the
+/// date span covered by the first of several pages.
+fn extrapolate_count(
+    first_page: &[Transaction],
+    start: Option<chrono::DateTime<chrono::Utc>>,
+    end: chrono::DateTime<chrono::Utc>,
+) -> Option<usize> {
+    let oldest = first_page
+        .iter()
+        .map(|transaction| transaction.date)
+        .min()?;
+    let newest = first_page
+        .iter()
+        .map(|transaction| transaction.date)
+        .max()?;
+
+    let covered = newest.signed_duration_since(oldest).num_milliseconds();
+    let total = end.signed_duration_since(start?).num_milliseconds();
+    if covered <= 0 || total <= 0 {
+        return None;
+    }
+
+    let estimate = i64::try_from(first_page.len())
+        .ok()?
+        .checked_mul(total)?
+        .checked_div(covered)?;
+
+    // There is at least one more page, so there is at least one more transaction.
+    Some(
+        usize::try_from(estimate)
+            .ok()?
+            .max(first_page.len().saturating_add(1)),
+    )
+}
+
+/// Settled transaction pages, fetched one request at a time.
+///
+/// Created by [`AkahuClient::transaction_pages`] or
+/// [`AkahuClient::resume_transaction_pages`]. The auto-paginating helpers such
+/// as [`AkahuClient::get_all_transactions`] are built on this.
+///
+/// Akahu may return an empty page with a `next` cursor while a backfill is in
+/// progress, so emptiness alone does not end pagination; only a missing cursor
+/// does. To avoid looping forever, [`next_page`](Self::next_page) fails if the
+/// API hands back the cursor that was just requested, or if more than
+/// 10,000 pages are fetched.
+pub struct TransactionPages<'a> {
+    /// The client to fetch pages with.
+    client: &'a AkahuClient,
+    /// The user whose transactions are being fetched.
+    user_token: &'a UserToken,
+    /// How to fetch the next page, or `None` once the last page was returned.
+    next: Option<PageRequest>,
+    /// How many pages have been fetched so far.
+    fetched: usize,
+}
+
+/// How to fetch the next page of a [`TransactionPages`].
+#[derive(Debug)]
+enum PageRequest {
+    /// Fetch the first page of a query.
+    First(TransactionQueryParams),
+    /// Fetch the page a cursor points to.
+    Resume(PaginationCursor),
+}
+
+impl TransactionPages<'_> {
+    /// Fetch the next page, or `None` once every page has been returned.
+    ///
+    /// If a request fails, the same page is requested again on the next call.
+    pub async fn next_page(&mut self) -> crate::error::AkahuResult<Option<TransactionPage>> {
+        if self.next.is_some() && self.fetched >= MAX_PAGES {
+            return Err(crate::error::AkahuError::Pagination {
+                message: format!("exceeded the maximum of {MAX_PAGES} pages"),
+            });
+        }
+
+        let page = match &self.next {
+            None => return Ok(None),
+            Some(PageRequest::First(query)) => {
+                self.client
+                    .get_transaction_page(self.user_token, query)
+                    .await?
+            }
+            Some(PageRequest::Resume(cursor)) => {
+                self.client
+                    .resume_transaction_page(self.user_token, cursor.clone())
+                    .await?
+            }
+        };
+
+        let next = page.resume_cursor();
+        if let (Some(PageRequest::Resume(current)), Some(next)) = (&self.next, &next) {
+            if current == next {
+                return Err(crate::error::AkahuError::Pagination {
+                    message: "cursor did not advance".to_string(),
+                });
+            }
+        }
+
+        self.next = next.map(PageRequest::Resume);
+        self.fetched = self.fetched.saturating_add(1);
+        Ok(Some(page))
+    }
+}
+
+/// The maximum number of pages a [`TransactionPages`] will fetch.
+///
+/// At 100 transactions per page this allows for a million transactions, well
+/// beyond any real account, so hitting it means the cursor is misbehaving.
+const MAX_PAGES: usize = 10_000;
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use crate::{
+        Account, AccountId, Cursor, PaginationCursor, TransactionId, TransactionQueryParams,
+        error::AkahuError,
+        test_utils::{account_json, mock_client, transaction_json, user_token},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_json, method, path, query_param, query_param_is_missing},
+    };
+
+    #[tokio::test]
+    async fn test_sync_transactions_since_advances_watermark() {
+        let server = MockServer::start().await;
+        let since = "2025-01-01T00:00:00.000Z".parse().unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("start", "2025-01-01T00:00:00.000Z"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_1", "2025-01-03T00:00:00.000Z", -10.0)],
+                "cursor": { "next": "cursor_2" }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("cursor", "cursor_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_2", "2025-01-02T00:00:00.000Z", 25.0)],
+                "cursor": { "next": null }
+            })))
+            .mount(&server)
+            .await;
+
+        let (transactions, watermark) = mock_client(&server)
+            .sync_transactions_since(&user_token(), since)
+            .await
+            .unwrap();
+
+        assert_eq!(transactions.len(), 2, "both pages should be collected");
+        assert_eq!(
+            watermark,
+            "2025-01-03T00:00:00.000Z"
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .unwrap(),
+            "watermark should be the latest transaction date"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_transactions_since_empty_delta() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [],
+                "cursor": { "next": null }
+            })))
+            .mount(&server)
+            .await;
+
+        let before = chrono::Utc::now();
+        let (transactions, watermark) = mock_client(&server)
+            .sync_transactions_since(&user_token(), before - chrono::Duration::days(1))
+            .await
+            .unwrap();
+
+        assert!(transactions.is_empty(), "no transactions were returned");
+        assert!(
+            watermark >= before,
+            "watermark should advance to the end of the queried range"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions/trans_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "item": transaction_json("trans_123", "2025-01-01T00:00:00.000Z", -10.0)
+            })))
+            .mount(&server)
+            .await;
+
+        let transaction = mock_client(&server)
+            .get_transaction(&user_token(), &TransactionId::new("trans_123").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(transaction.id.as_str(), "trans_123");
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_not_found() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions/trans_missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "success": false,
+                "message": "Transaction not found"
+            })))
+            .mount(&server)
+            .await;
+
+        let error = mock_client(&server)
+            .get_transaction(&user_token(), &TransactionId::new("trans_missing").unwrap())
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, AkahuError::NotFound { ref message } if message == "Transaction not found"),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_transactions_by_ids() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/transactions/ids"))
+            .and(body_json(serde_json::json!(["trans_1", "trans_2"])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [
+                    transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.0),
+                    transaction_json("trans_2", "2025-01-02T00:00:00.000Z", 20.0)
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let ids = [
+            TransactionId::new("trans_1").unwrap(),
+            TransactionId::new("trans_2").unwrap(),
+        ];
+        let transactions = mock_client(&server)
+            .get_transactions_by_ids(&user_token(), &ids)
+            .await
+            .unwrap();
+
+        let returned: Vec<_> = transactions.iter().map(|t| t.id.clone()).collect();
+        assert_eq!(
+            returned, ids,
+            "transactions should map back to the requested ids"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_all_transactions_reports_progress() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [
+                    transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.0),
+                    transaction_json("trans_2", "2025-01-02T00:00:00.000Z", -20.0)
+                ],
+                "cursor": { "next": "cursor_2" }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("cursor", "cursor_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_3", "2025-01-03T00:00:00.000Z", 5.0)],
+                "cursor": { "next": null }
+            })))
+            .mount(&server)
+            .await;
+
+        let mut pages = Vec::new();
+        let transactions = mock_client(&server)
+            .get_all_transactions_with_progress(
+                &user_token(),
+                &Default::default(),
+                |index, len, more| {
+                    pages.push((index, len, more));
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(transactions.len(), 3, "both pages should be collected");
+        assert_eq!(
+            pages,
+            vec![(0, 2, true), (1, 1, false)],
+            "callback should fire once per page"
+        );
+    }
+
+    /// Mount two pages holding three transactions in total.
+    async fn mount_export_pages(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [
+                    transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.0),
+                    transaction_json("trans_2", "2025-01-02T00:00:00.000Z", -20.0)
+                ],
+                "cursor": { "next": "cursor_2" }
+            })))
+            .mount(server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("cursor", "cursor_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_3", "2025-01-03T00:00:00.000Z", 5.0)],
+                "cursor": { "next": null }
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_transaction_pages() {
+        let server = MockServer::start().await;
+        mount_export_pages(&server).await;
+
+        let client = mock_client(&server);
+        let token = user_token();
+        let mut pages = client.transaction_pages(&token, TransactionQueryParams::new());
+
+        let first = pages.next_page().await.unwrap().unwrap();
+        assert_eq!(first.items.len(), 2);
+        let checkpoint = first.resume_cursor().unwrap();
+
+        let second = pages.next_page().await.unwrap().unwrap();
+        assert_eq!(second.items.first().unwrap().id.as_str(), "trans_3");
+        assert!(second.resume_cursor().is_none());
+
+        assert!(pages.next_page().await.unwrap().is_none());
+
+        let mut resumed = client.resume_transaction_pages(&token, checkpoint);
+        assert_eq!(resumed.next_page().await.unwrap().unwrap(), second);
+    }
+
+    #[tokio::test]
+    async fn test_export_transactions_ndjson() {
+        let server = MockServer::start().await;
+        mount_export_pages(&server).await;
+
+        let mut output = Vec::new();
+        let written = mock_client(&server)
+            .export_transactions_ndjson(&user_token(), &Default::default(), &mut output)
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let ids: Vec<String> = output
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<crate::Transaction>(line)
+                    .unwrap()
+                    .id
+                    .to_string()
+            })
+            .collect();
+
+        assert_eq!(written, 3, "all transactions should be counted");
+        assert_eq!(
+            ids,
+            ["trans_1", "trans_2", "trans_3"],
+            "one line per transaction"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_transactions_json() {
+        let server = MockServer::start().await;
+        mount_export_pages(&server).await;
+
+        for pretty in [false, true] {
+            let mut output = Vec::new();
+            let written = mock_client(&server)
+                .export_transactions_json(&user_token(), &Default::default(), &mut output, pretty)
+                .await
+                .unwrap();
+
+            let transactions: Vec<crate::Transaction> = serde_json::from_slice(&output).unwrap();
+            let ids: Vec<&str> = transactions.iter().map(|t| t.id.as_str()).collect();
+
+            assert_eq!(written, 3, "all transactions should be counted");
+            assert_eq!(ids, ["trans_1", "trans_2", "trans_3"]);
+
+            let mut expected = Vec::new();
+            crate::write_transactions_json(&mut expected, &transactions, pretty).unwrap();
+            assert_eq!(
+                output, expected,
+                "streamed output should match pretty={pretty}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_all_transactions_follows_empty_pages() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [],
+                "cursor": { "next": "cursor_2" }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("cursor", "cursor_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.0)],
+                "cursor": { "next": null }
+            })))
+            .mount(&server)
+            .await;
+
+        let transactions = mock_client(&server)
+            .get_all_transactions(&user_token(), &Default::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            transactions.len(),
+            1,
+            "the empty page should not end pagination"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_all_transactions_stalled_cursor() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [],
+                "cursor": { "next": "cursor_2" }
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let result = mock_client(&server)
+            .get_all_transactions(&user_token(), &Default::default())
+            .await;
+
+        assert!(
+            matches!(result, Err(AkahuError::Pagination { .. })),
+            "expected a pagination error, got {result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_transaction_page_reuses_query() {
+        let server = MockServer::start().await;
+        let query = TransactionQueryParams::new()
+            .start("2025-01-01T00:00:00.000Z".parse().unwrap())
+            .end("2025-02-01T00:00:00.000Z".parse().unwrap());
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("start", "2025-01-01T00:00:00.000Z"))
+            .and(query_param("end", "2025-02-01T00:00:00.000Z"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_1", "2025-01-03T00:00:00.000Z", -10.0)],
+                "cursor": { "next": "cursor_2" }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("start", "2025-01-01T00:00:00.000Z"))
+            .and(query_param("end", "2025-02-01T00:00:00.000Z"))
+            .and(query_param("cursor", "cursor_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_2", "2025-01-02T00:00:00.000Z", 25.0)],
+                "cursor": { "next": null }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let first = client
+            .get_transaction_page(&user_token(), &query)
+            .await
+            .unwrap();
+        assert!(first.has_more(), "the first page has a cursor");
+
+        let second = client
+            .get_next_transaction_page(&user_token(), first)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(second.query(), &query, "bounds should carry over");
+        assert_eq!(second.items.len(), 1, "expected the second page");
+        assert!(!second.has_more(), "the second page is the last");
+
+        let third = client
+            .get_next_transaction_page(&user_token(), second)
+            .await
+            .unwrap();
+        assert!(third.is_none(), "no request is made past the last page");
+    }
+
+    #[tokio::test]
+    async fn test_resume_transaction_page() {
+        let server = MockServer::start().await;
+        let query =
+            TransactionQueryParams::new().start("2025-01-01T00:00:00.000Z".parse().unwrap());
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_1", "2025-01-03T00:00:00.000Z", -10.0)],
+                "cursor": { "next": "cursor_2" }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .and(query_param("start", "2025-01-01T00:00:00.000Z"))
+            .and(query_param("cursor", "cursor_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_2", "2025-01-02T00:00:00.000Z", 25.0)],
+                "cursor": { "next": null }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let first = client
+            .get_transaction_page(&user_token(), &query)
+            .await
+            .unwrap();
+
+        let persisted = serde_json::to_string(&first.resume_cursor().unwrap()).unwrap();
+        let restored: PaginationCursor = serde_json::from_str(&persisted).unwrap();
+        assert_eq!(restored.query(), &query, "the query should round-trip");
+
+        let second = client
+            .resume_transaction_page(&user_token(), restored)
+            .await
+            .unwrap();
+        assert_eq!(second.items.first().unwrap().id.as_str(), "trans_2");
+        assert!(
+            second.resume_cursor().is_none(),
+            "the second page is the last"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resume_transaction_page_wrong_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let cursor = PaginationCursor::new(
+            "accounts/acc_123/transactions",
+            TransactionQueryParams::new(),
+            Cursor::new("cursor_2"),
+        );
+        let result = mock_client(&server)
+            .resume_transaction_page(&user_token(), cursor)
+            .await;
+
+        assert!(
+            matches!(result, Err(AkahuError::Pagination { .. })),
+            "expected a pagination error, got {result:?}"
+        );
+    }
+
+    async fn mount_first_page(server: &MockServer, items: serde_json::Value, next: Option<&str>) {
+        Mock::given(method("GET"))
+            .and(path("/transactions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": items,
+                "cursor": { "next": next }
+            })))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_estimate_transaction_count_extrapolates_full_page() {
+        let server = MockServer::start().await;
+        mount_first_page(
+            &server,
+            serde_json::json!([
+                transaction_json("trans_2", "2025-01-10T00:00:00.000Z", -20.0),
+                transaction_json("trans_1", "2025-01-09T00:00:00.000Z", -10.0)
+            ]),
+            Some("cursor_2"),
+        )
+        .await;
+
+        let client = mock_client(&server);
+        let query = TransactionQueryParams::new()
+            .start("2025-01-01T00:00:00Z".parse().unwrap())
+            .end("2025-01-11T00:00:00Z".parse().unwrap());
+        let estimate = client
+            .estimate_transaction_count(&user_token(), &query)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            estimate,
+            Some(20),
+            "two transactions a day over ten days should extrapolate to twenty"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_estimate_transaction_count_is_exact_for_short_page() {
+        let server = MockServer::start().await;
+        mount_first_page(
+            &server,
+            serde_json::json!([
+                transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.0),
+                transaction_json("trans_2", "2025-01-02T00:00:00.000Z", -20.0),
+                transaction_json("trans_3", "2025-01-03T00:00:00.000Z", 5.0)
+            ]),
+            None,
+        )
+        .await;
+
+        let client = mock_client(&server);
+        let estimate = client
+            .estimate_transaction_count(&user_token(), &TransactionQueryParams::new())
+            .await
+            .unwrap();
+
+        assert_eq!(estimate, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_estimate_transaction_count_needs_start() {
+        let server = MockServer::start().await;
+        mount_first_page(
+            &server,
+            serde_json::json!([
+                transaction_json("trans_2", "2025-01-10T00:00:00.000Z", -20.0),
+                transaction_json("trans_1", "2025-01-09T00:00:00.000Z", -10.0)
+            ]),
+            Some("cursor_2"),
+        )
+        .await;
+
+        let client = mock_client(&server);
+        let estimate = client
+            .estimate_transaction_count(&user_token(), &TransactionQueryParams::new())
+            .await
+            .unwrap();
+
+        assert_eq!(estimate, None, "an unbounded query cannot be extrapolated");
+    }
+
+    #[tokio::test]
+    async fn test_get_account_transactions_accepts_id_or_account() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/accounts/acc_123/transactions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [transaction_json("trans_1", "2025-01-01T00:00:00.000Z", -10.0)],
+                "cursor": { "next": null }
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let account: Account = serde_json::from_value(account_json("acc_123")).unwrap();
+        let account_id = AccountId::new("acc_123").unwrap();
+
+        let by_id = client
+            .get_account_transactions(&user_token(), &account_id, None, None, None)
+            .await
+            .unwrap();
+        let by_account = client
+            .get_account_transactions(&user_token(), &account, None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(by_id.items, by_account.items);
+    }
 }
@@ -0,0 +1,188 @@
+//! Splitting long date ranges into smaller query windows.
+
+use chrono::{DateTime, Datelike, Months, Utc};
+
+/// How far each window extends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChunkSize {
+    /// Windows end at the start of each calendar month (UTC).
+    Monthly,
+    /// Windows span a fixed duration.
+    Fixed(chrono::Duration),
+}
+
+/// An iterator over consecutive `(start, end)` windows covering a date range.
+///
+/// Fetching years of transactions in a single pagination run is slow, and a
+/// failure part way through loses all progress. Windowing the range lets each
+/// chunk be fetched (and retried) independently.
+///
+/// Windows follow Akahu's query semantics, where `start` is exclusive and `end`
+/// is inclusive. Each window starts exactly where the previous one ended, so
+/// passing each pair as the `start` and `end` of a transaction query covers
+/// the whole range with no gaps or overlap.
+///
+/// ```
+/// use akahu_client::DateRangeChunks;
+///
+/// let start = "2025-01-01T00:00:00Z".parse().unwrap();
+/// let end = "2025-04-01T00:00:00Z".parse().unwrap();
+///
+/// assert_eq!(DateRangeChunks::monthly(start, end).count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateRangeChunks {
+    /// The start of the next window, or `None` once the range is exhausted.
+    next_start: Option<DateTime<Utc>>,
+    /// The end of the whole range.
+    end: DateTime<Utc>,
+    /// How far each window extends.
+    size: ChunkSize,
+}
+
+impl DateRangeChunks {
+    /// Split `start..=end` into windows ending at each calendar month boundary.
+    ///
+    /// The first and last windows may be partial months if `start` or `end` do
+    /// not fall on a month boundary.
+    pub const fn monthly(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self::new(start, end, ChunkSize::Monthly)
+    }
+
+    /// Split `start..=end` into windows of `days` days each.
+    ///
+    /// The last window may be shorter. A `days` of zero is treated as one.
+    pub fn by_days(start: DateTime<Utc>, end: DateTime<Utc>, days: u32) -> Self {
+        let duration = chrono::Duration::days(i64::from(days.max(1)));
+        Self::new(start, end, ChunkSize::Fixed(duration))
+    }
+
+    const fn new(start: DateTime<Utc>, end: DateTime<Utc>, size: ChunkSize) -> Self {
+        Self {
+            next_start: Some(start),
+            end,
+            size,
+        }
+    }
+
+    /// The boundary following `start`, or `None` if it would overflow.
+    fn next_boundary(&self, start: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.size {
+            ChunkSize::Monthly => start
+                .date_naive()
+                .with_day(1)?
+                .checked_add_months(Months::new(1))?
+                .and_hms_opt(0, 0, 0)
+                .map(|boundary| boundary.and_utc()),
+            ChunkSize::Fixed(duration) => start.checked_add_signed(duration),
+        }
+    }
+}
+
+impl Iterator for DateRangeChunks {
+    type Item = (DateTime<Utc>, DateTime<Utc>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next_start.take()?;
+        if start >= self.end {
+            return None;
+        }
+
+        let window_end = self
+            .next_boundary(start)
+            .map_or(self.end, |boundary| boundary.min(self.end));
+        if window_end < self.end {
+            self.next_start = Some(window_end);
+        }
+
+        Some((start, window_end))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+
+    fn timestamp(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    fn assert_contiguous(chunks: &[(DateTime<Utc>, DateTime<Utc>)], start: &str, end: &str) {
+        assert_eq!(chunks.first().unwrap().0, timestamp(start));
+        assert_eq!(chunks.last().unwrap().1, timestamp(end));
+        for pair in chunks.windows(2) {
+            let (previous, next) = (pair.first().unwrap(), pair.last().unwrap());
+            assert_eq!(previous.1, next.0, "windows should neither gap nor overlap");
+        }
+    }
+
+    #[test]
+    fn test_monthly_chunks() {
+        let chunks: Vec<_> = DateRangeChunks::monthly(
+            timestamp("2025-01-01T00:00:00Z"),
+            timestamp("2025-04-01T00:00:00Z"),
+        )
+        .collect();
+
+        assert_eq!(
+            chunks,
+            vec![
+                (
+                    timestamp("2025-01-01T00:00:00Z"),
+                    timestamp("2025-02-01T00:00:00Z")
+                ),
+                (
+                    timestamp("2025-02-01T00:00:00Z"),
+                    timestamp("2025-03-01T00:00:00Z")
+                ),
+                (
+                    timestamp("2025-03-01T00:00:00Z"),
+                    timestamp("2025-04-01T00:00:00Z")
+                ),
+            ]
+        );
+        assert_contiguous(&chunks, "2025-01-01T00:00:00Z", "2025-04-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_monthly_chunks_partial_months() {
+        let chunks: Vec<_> = DateRangeChunks::monthly(
+            timestamp("2024-12-15T08:30:00Z"),
+            timestamp("2025-02-10T12:00:00Z"),
+        )
+        .collect();
+
+        assert_eq!(chunks.len(), 3, "December, January and part of February");
+        assert_eq!(chunks.first().unwrap().1, timestamp("2025-01-01T00:00:00Z"));
+        assert_contiguous(&chunks, "2024-12-15T08:30:00Z", "2025-02-10T12:00:00Z");
+    }
+
+    #[test]
+    fn test_by_days_chunks() {
+        let chunks: Vec<_> = DateRangeChunks::by_days(
+            timestamp("2025-01-01T00:00:00Z"),
+            timestamp("2025-01-25T00:00:00Z"),
+            7,
+        )
+        .collect();
+
+        assert_eq!(chunks.len(), 4, "three full weeks and a shorter remainder");
+        assert_eq!(chunks.get(1).unwrap().0, timestamp("2025-01-08T00:00:00Z"));
+        assert_contiguous(&chunks, "2025-01-01T00:00:00Z", "2025-01-25T00:00:00Z");
+    }
+
+    #[test]
+    fn test_empty_range() {
+        let start = timestamp("2025-01-01T00:00:00Z");
+
+        assert_eq!(DateRangeChunks::monthly(start, start).count(), 0);
+        assert_eq!(
+            DateRangeChunks::by_days(start, timestamp("2024-01-01T00:00:00Z"), 7).count(),
+            0
+        );
+    }
+}
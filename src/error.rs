@@ -48,6 +48,13 @@ pub enum AkahuError {
         message: String,
     },
 
+    /// Unprocessable entity - one or more request fields failed validation
+    #[error("Validation failed: {}", .errors.iter().map(|e| format!("{}: {}", e.field, e.message)).collect::<Vec<_>>().join(", "))]
+    ValidationFailed {
+        /// The field-level validation errors
+        errors: Vec<crate::models::FieldError>,
+    },
+
     /// Generic API error with status code and message
     #[error("API error {status}: {message}")]
     ApiError {
@@ -79,6 +86,44 @@ pub enum AkahuError {
         source_string: Option<String>,
     },
 
+    /// Auto-pagination stopped because the cursor was not advancing
+    #[error("Pagination error: {message}")]
+    Pagination {
+        /// Description of why pagination stopped
+        message: String,
+    },
+
+    /// JSON serialization error
+    #[error("JSON serialization error: {0}")]
+    JsonSerialization(serde_json::Error),
+
+    /// IO error while writing output
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An ID did not have the expected prefix
+    #[error(transparent)]
+    InvalidId(#[from] crate::InvalidIdError),
+
+    /// A bank account number could not be parsed
+    #[error(transparent)]
+    InvalidBankAccount(#[from] crate::InvalidBankAccountError),
+
+    /// A redirect URI was not an absolute HTTPS URL
+    #[error(transparent)]
+    InvalidRedirectUri(#[from] crate::InvalidRedirectUri),
+
+    /// The session was not granted a scope required for this call
+    #[error("Missing scope: {0}")]
+    MissingScope(crate::Scope),
+
+    /// A request was rejected locally before being sent
+    #[error("Validation error: {message}")]
+    Validation {
+        /// Description of what was invalid
+        message: String,
+    },
+
     /// Missing app secret - call with_app_secret() first for app-scoped endpoints
     #[error("Missing app secret - call with_app_secret() first")]
     MissingAppSecret,
@@ -94,5 +139,399 @@ pub enum AkahuError {
     },
 }
 
+/// Broad classification of an [`AkahuError`], as returned by [`AkahuError::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AkahuErrorKind {
+    /// Akahu responded with an error, or with a body that could not be parsed.
+    Api,
+    /// The request could not be sent or its response could not be received.
+    Transport,
+    /// The error happened locally before anything was sent, e.g. invalid input
+    /// or a request that could not be built.
+    Client,
+}
+
+impl AkahuError {
+    /// Classify the error by where it originated.
+    ///
+    /// Useful for deciding how to handle an error without matching on every
+    /// variant, e.g. only surfacing [`Api`](AkahuErrorKind::Api) errors to users
+    /// while logging [`Client`](AkahuErrorKind::Client) errors as bugs.
+    ///
+    /// [`Network`](Self::Network) errors are split by cause: a request that
+    /// could not be built (e.g. its JSON body failed to serialize) is a client
+    /// error, a response body that could not be decoded is an API error, and
+    /// everything else is a transport error.
+    pub fn kind(&self) -> AkahuErrorKind {
+        match self {
+            Self::BadRequest { .. }
+            | Self::Unauthorized { .. }
+            | Self::Forbidden { .. }
+            | Self::NotFound { .. }
+            | Self::RateLimited { .. }
+            | Self::InternalServerError { .. }
+            | Self::ValidationFailed { .. }
+            | Self::ApiError { .. }
+            | Self::JsonDeserialization { .. }
+            | Self::OAuth { .. } => AkahuErrorKind::Api,
+            Self::Network(error) if error.is_builder() => AkahuErrorKind::Client,
+            Self::Network(error) if error.is_decode() => AkahuErrorKind::Api,
+            Self::Network(_) => AkahuErrorKind::Transport,
+            Self::InvalidHeaderValue(_)
+            | Self::UrlParse(_)
+            | Self::Pagination { .. }
+            | Self::JsonSerialization(_)
+            | Self::Io(_)
+            | Self::InvalidId(_)
+            | Self::InvalidBankAccount(_)
+            | Self::InvalidRedirectUri(_)
+            | Self::MissingScope(_)
+            | Self::Validation { .. }
+            | Self::MissingAppSecret => AkahuErrorKind::Client,
+        }
+    }
+
+    /// Whether Akahu responded with this error.
+    pub fn is_api(&self) -> bool {
+        matches!(self.kind(), AkahuErrorKind::Api)
+    }
+
+    /// Build the error for an unsuccessful response from its status code and body.
+    ///
+    /// Akahu error bodies are usually `{"success": false, "message": "..."}`, which
+    /// is mapped to a variant by status code. The OAuth endpoints instead return
+    /// `{"error": "...", "error_description": "..."}`, which becomes
+    /// [`AkahuError::OAuth`]. A 422 with a list of field `errors` becomes
+    /// [`AkahuError::ValidationFailed`]. If the body is none of these, the status
+    /// code's canonical reason is used as the message.
+    pub fn from_status_and_body(status: u16, body: &[u8]) -> Self {
+        let status_code = reqwest::StatusCode::from_u16(status).ok();
+
+        if status_code == Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY) {
+            if let Ok(validation) =
+                serde_json::from_slice::<crate::models::ValidationErrorResponse>(body)
+            {
+                return Self::ValidationFailed {
+                    errors: validation.errors,
+                };
+            }
+        }
+
+        let message = match serde_json::from_slice::<crate::models::ErrorResponse>(body) {
+            Ok(error_body) => error_body.message,
+            Err(_) => {
+                if let Ok(oauth) = serde_json::from_slice::<crate::models::OAuthErrorResponse>(body)
+                {
+                    return Self::OAuth {
+                        error: oauth.error,
+                        error_description: oauth.error_description,
+                    };
+                }
+                status_code
+                    .and_then(|status| status.canonical_reason())
+                    .unwrap_or("Unknown error")
+                    .to_string()
+            }
+        };
+
+        match status_code {
+            Some(reqwest::StatusCode::BAD_REQUEST) => Self::BadRequest { message, status },
+            Some(reqwest::StatusCode::UNAUTHORIZED) => Self::Unauthorized { message },
+            Some(reqwest::StatusCode::FORBIDDEN) => Self::Forbidden { message },
+            Some(reqwest::StatusCode::NOT_FOUND) => Self::NotFound { message },
+            Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => Self::RateLimited { message },
+            Some(reqwest::StatusCode::INTERNAL_SERVER_ERROR) => {
+                Self::InternalServerError { message }
+            }
+            Some(_) | None => Self::ApiError { status, message },
+        }
+    }
+
+    /// A short, non-technical description of the error, suitable for showing to
+    /// end users.
+    ///
+    /// Unlike [`Display`](std::fmt::Display), this never includes messages from
+    /// the API or other internal details. Each variant maps to a fixed string, so
+    /// the result can also be used as a key for localisation.
+    pub const fn user_message(&self) -> &'static str {
+        match self {
+            Self::Unauthorized { .. } | Self::MissingScope(_) | Self::OAuth { .. } => {
+                "Your bank connection needs to be re-authorised. Please connect your accounts again."
+            }
+            Self::Forbidden { .. } => "You don't have permission to do that.",
+            Self::NotFound { .. } => "We couldn't find what you were looking for.",
+            Self::RateLimited { .. } => "Too many requests, please try again shortly.",
+            Self::InternalServerError { .. } | Self::ApiError { .. } => {
+                "Something went wrong on our end. Please try again later."
+            }
+            Self::Network(_) => {
+                "We couldn't connect. Please check your internet connection and try again."
+            }
+            Self::BadRequest { .. }
+            | Self::InvalidId(_)
+            | Self::InvalidBankAccount(_)
+            | Self::InvalidRedirectUri(_)
+            | Self::Validation { .. }
+            | Self::ValidationFailed { .. } => {
+                "Some of the details provided weren't valid. Please check them and try again."
+            }
+            Self::InvalidHeaderValue(_)
+            | Self::UrlParse(_)
+            | Self::JsonDeserialization { .. }
+            | Self::Pagination { .. }
+            | Self::JsonSerialization(_)
+            | Self::Io(_)
+            | Self::MissingAppSecret => "Something went wrong. Please try again later.",
+        }
+    }
+}
+
 /// Convenience type alias for Results using AkahuError
 pub type AkahuResult<T> = std::result::Result<T, AkahuError>;
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        AccountId, AkahuClient, BankAccountNumber,
+        models::{Account, ItemResponse},
+        test_utils::{mock_client, user_token},
+    };
+    use wiremock::MockServer;
+
+    async fn parse_and_fetch(
+        client: &AkahuClient,
+        account_id: &str,
+    ) -> AkahuResult<ItemResponse<Account>> {
+        let account_id = AccountId::new(account_id)?;
+        client.get_account(&user_token(), &account_id).await
+    }
+
+    #[tokio::test]
+    async fn test_invalid_id_converts_with_question_mark() {
+        let server = MockServer::start().await;
+        let result = parse_and_fetch(&mock_client(&server), "conn_123").await;
+
+        assert!(
+            matches!(result, Err(AkahuError::InvalidId(_))),
+            "expected InvalidId, got {result:?}"
+        );
+    }
+
+    fn error_body(message: &str) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({ "success": false, "message": message })).unwrap()
+    }
+
+    #[test]
+    fn test_from_status_and_body() {
+        let cases: [(u16, fn(&AkahuError) -> bool); 7] = [
+            (400, |e| {
+                matches!(e, AkahuError::BadRequest { status: 400, .. })
+            }),
+            (401, |e| matches!(e, AkahuError::Unauthorized { .. })),
+            (403, |e| matches!(e, AkahuError::Forbidden { .. })),
+            (404, |e| matches!(e, AkahuError::NotFound { .. })),
+            (429, |e| matches!(e, AkahuError::RateLimited { .. })),
+            (500, |e| matches!(e, AkahuError::InternalServerError { .. })),
+            (418, |e| {
+                matches!(e, AkahuError::ApiError { status: 418, .. })
+            }),
+        ];
+
+        for (status, is_expected) in cases {
+            let error = AkahuError::from_status_and_body(status, &error_body("Something broke"));
+            assert!(
+                is_expected(&error),
+                "unexpected variant for {status}: {error:?}"
+            );
+            assert!(
+                error.to_string().contains("Something broke"),
+                "message should come from the body for {status}: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_status_and_body_without_envelope() {
+        let error = AkahuError::from_status_and_body(404, b"<html>not json</html>");
+        assert!(
+            matches!(&error, AkahuError::NotFound { message } if message == "Not Found"),
+            "expected the canonical reason, got {error:?}"
+        );
+
+        let error = AkahuError::from_status_and_body(
+            400,
+            br#"{"error": "invalid_grant", "error_description": "Code has expired"}"#,
+        );
+        assert!(
+            matches!(&error, AkahuError::OAuth { error, .. } if error == "invalid_grant"),
+            "expected an OAuth error, got {error:?}"
+        );
+    }
+
+    #[test]
+    fn test_invalid_bank_account_converts() {
+        let result: AkahuResult<BankAccountNumber> =
+            BankAccountNumber::new("not a number").map_err(AkahuError::from);
+
+        assert!(
+            matches!(result, Err(AkahuError::InvalidBankAccount(_))),
+            "expected InvalidBankAccount, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_user_message() {
+        let rate_limited = AkahuError::from_status_and_body(429, &error_body("Slow down"));
+        assert_eq!(
+            rate_limited.user_message(),
+            "Too many requests, please try again shortly."
+        );
+
+        let unauthorized = AkahuError::from_status_and_body(401, &error_body("Token revoked"));
+        assert!(unauthorized.user_message().contains("re-authorised"));
+        assert_eq!(
+            AkahuError::MissingScope(crate::Scope::Payments).user_message(),
+            unauthorized.user_message(),
+            "both are fixed by reconnecting"
+        );
+
+        let server_error = AkahuError::from_status_and_body(500, &error_body("db timeout"));
+        assert!(
+            !server_error.user_message().contains("db timeout"),
+            "API messages must not leak into user messages"
+        );
+
+        let invalid = BankAccountNumber::new("not a number").map_err(AkahuError::from);
+        assert!(
+            invalid
+                .unwrap_err()
+                .user_message()
+                .contains("weren't valid")
+        );
+    }
+
+    #[test]
+    fn test_from_status_and_body_validation_failed() {
+        let body = serde_json::json!({
+            "success": false,
+            "message": "Validation failed",
+            "errors": [
+                { "field": "amount", "message": "must be greater than 0" },
+                { "field": "to.account_number", "message": "is not a valid account number" }
+            ]
+        });
+
+        let error = AkahuError::from_status_and_body(422, &serde_json::to_vec(&body).unwrap());
+
+        let AkahuError::ValidationFailed { errors } = &error else {
+            panic!("expected ValidationFailed, got {error:?}");
+        };
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["amount", "to.account_number"]);
+        assert_eq!(
+            error.to_string(),
+            "Validation failed: amount: must be greater than 0, \
+             to.account_number: is not a valid account number"
+        );
+
+        let plain = AkahuError::from_status_and_body(422, &error_body("Nope"));
+        assert!(
+            matches!(plain, AkahuError::ApiError { status: 422, .. }),
+            "a 422 without field errors stays generic"
+        );
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(
+            AkahuError::from_status_and_body(404, &error_body("Missing")).kind(),
+            AkahuErrorKind::Api
+        );
+        assert_eq!(
+            AkahuError::from_status_and_body(400, br#"{"error": "invalid_grant"}"#).kind(),
+            AkahuErrorKind::Api
+        );
+        assert_eq!(
+            AkahuError::Validation {
+                message: "bad".to_string()
+            }
+            .kind(),
+            AkahuErrorKind::Client
+        );
+
+        let invalid = BankAccountNumber::new("not a number").map_err(AkahuError::from);
+        assert_eq!(invalid.unwrap_err().kind(), AkahuErrorKind::Client);
+
+        let json = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        let error = AkahuError::JsonSerialization(json);
+        assert_eq!(error.kind(), AkahuErrorKind::Client);
+        assert!(!error.is_api());
+    }
+
+    #[test]
+    fn test_kind_of_builder_error() {
+        let error = reqwest::Client::new()
+            .get("not a url")
+            .build()
+            .map_err(AkahuError::from)
+            .unwrap_err();
+
+        assert!(
+            matches!(error, AkahuError::Network(_)),
+            "builder errors surface as Network, got {error:?}"
+        );
+        assert_eq!(
+            error.kind(),
+            AkahuErrorKind::Client,
+            "a request that could not be built never left the client"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kind_of_undecodable_response() {
+        let server = MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::path("/accounts"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_string("not json"))
+            .mount(&server)
+            .await;
+
+        let error = mock_client(&server)
+            .get_accounts(&user_token())
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, AkahuError::JsonDeserialization { .. }),
+            "expected a deserialization error, got {error:?}"
+        );
+        assert_eq!(
+            error.kind(),
+            AkahuErrorKind::Api,
+            "the server sent the unparseable body"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_kind_of_connection_error() {
+        // Nothing listens on a port that was just released.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let client = AkahuClient::new(
+            reqwest::Client::new(),
+            crate::test_utils::APP_TOKEN,
+            Some(format!("http://127.0.0.1:{port}")),
+        );
+
+        let error = client.get_accounts(&user_token()).await.unwrap_err();
+
+        assert_eq!(error.kind(), AkahuErrorKind::Transport);
+    }
+}
@@ -0,0 +1,153 @@
+//! Writing transactions out as JSON.
+
+use serde::Serialize;
+
+use crate::{Transaction, error::AkahuResult};
+
+/// Write transactions to `writer` as a JSON array.
+///
+/// Transactions are serialized one at a time straight into the writer rather
+/// than into an intermediate string. With `pretty` set, the output matches
+/// [`serde_json::to_string_pretty`].
+///
+/// To stream transactions into a JSON array while they are still being fetched,
+/// see [`AkahuClient::export_transactions_json`](crate::AkahuClient::export_transactions_json).
+pub fn write_transactions_json<W: std::io::Write>(
+    mut writer: W,
+    transactions: &[Transaction],
+    pretty: bool,
+) -> AkahuResult<()> {
+    let mut encoder = JsonArrayEncoder::new(pretty);
+    let mut buffer = Vec::new();
+
+    for transaction in transactions {
+        encoder.push(&mut buffer, transaction)?;
+        writer.write_all(&buffer)?;
+        buffer.clear();
+    }
+
+    encoder.finish(&mut buffer);
+    writer.write_all(&buffer)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Incrementally encodes items as the elements of a JSON array.
+///
+/// Produces the same bytes as serializing the whole array at once, without
+/// needing every element up front.
+#[derive(Debug)]
+pub struct JsonArrayEncoder {
+    /// Whether to match `serde_json`'s pretty formatting.
+    pretty: bool,
+    /// How many elements have been encoded so far.
+    written: usize,
+}
+
+impl JsonArrayEncoder {
+    /// Indentation `serde_json` uses for pretty output.
+    const INDENT: &[u8] = b"  ";
+
+    pub const fn new(pretty: bool) -> Self {
+        Self { pretty, written: 0 }
+    }
+
+    /// The number of elements encoded so far.
+    pub const fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Append the next element, preceded by the opening bracket or a separator.
+    pub fn push<T: Serialize>(&mut self, buffer: &mut Vec<u8>, item: &T) -> AkahuResult<()> {
+        let prefix: &[u8] = match (self.written, self.pretty) {
+            (0, false) => b"[",
+            (0, true) => b"[\n",
+            (_, false) => b",",
+            (_, true) => b",\n",
+        };
+        buffer.extend_from_slice(prefix);
+
+        if self.pretty {
+            // Strings never contain a raw newline, so every newline in the pretty
+            // output starts a new line that needs indenting one level deeper.
+            let element = serde_json::to_vec_pretty(item)
+                .map_err(crate::error::AkahuError::JsonSerialization)?;
+            buffer.extend_from_slice(Self::INDENT);
+            for byte in element {
+                buffer.push(byte);
+                if byte == b'\n' {
+                    buffer.extend_from_slice(Self::INDENT);
+                }
+            }
+        } else {
+            serde_json::to_writer(&mut *buffer, item)
+                .map_err(crate::error::AkahuError::JsonSerialization)?;
+        }
+
+        self.written = self.written.saturating_add(1);
+        Ok(())
+    }
+
+    /// Append the closing bracket, or an empty array if nothing was pushed.
+    pub fn finish(&self, buffer: &mut Vec<u8>) {
+        let suffix: &[u8] = match (self.written, self.pretty) {
+            (0, _) => b"[]",
+            (_, false) => b"]",
+            (_, true) => b"\n]",
+        };
+        buffer.extend_from_slice(suffix);
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::test_utils::transaction_json;
+
+    fn transactions() -> Vec<Transaction> {
+        vec![
+            serde_json::from_value(transaction_json(
+                "trans_1",
+                "2025-01-01T00:00:00.000Z",
+                -10.5,
+            ))
+            .unwrap(),
+            serde_json::from_value(transaction_json(
+                "trans_2",
+                "2025-01-02T00:00:00.000Z",
+                20.0,
+            ))
+            .unwrap(),
+        ]
+    }
+
+    fn write(transactions: &[Transaction], pretty: bool) -> String {
+        let mut output = Vec::new();
+        write_transactions_json(&mut output, transactions, pretty).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_matches_serde_json_output() {
+        let transactions = transactions();
+
+        assert_eq!(
+            write(&transactions, false),
+            serde_json::to_string(&transactions).unwrap()
+        );
+        assert_eq!(
+            write(&transactions, true),
+            serde_json::to_string_pretty(&transactions).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_empty_array() {
+        assert_eq!(write(&[], false), "[]");
+        assert_eq!(write(&[], true), "[]");
+    }
+}
@@ -0,0 +1,163 @@
+//! Client-side transaction filtering.
+//!
+//! Akahu only filters transactions by date range (there is no `category` or
+//! merchant query parameter), so anything more specific has to be done once
+//! the transactions have been fetched. [`TransactionFilter`]
+//! describes such a filter as a small predicate tree that can be built once and
+//! applied to many transactions.
+
+use rust_decimal::Decimal;
+
+use crate::{AccountId, CategoryId, MerchantId, Transaction};
+
+/// A predicate over [`Transaction`]s.
+///
+/// Leaf variants test a single property; [`AllOf`](Self::AllOf) and
+/// [`AnyOf`](Self::AnyOf) combine other filters, so "debits over $100 or
+/// anything from merchant X" can be written as:
+///
+/// ```
+/// # use akahu_client::{MerchantId, TransactionFilter};
+/// # use rust_decimal::Decimal;
+/// let filter = TransactionFilter::any_of(vec![
+///     TransactionFilter::all_of(vec![
+///         TransactionFilter::Debit,
+///         TransactionFilter::MinAmount(Decimal::ONE_HUNDRED),
+///     ]),
+///     TransactionFilter::Merchant(MerchantId::new("_merchant_123").unwrap()),
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionFilter {
+    /// Money leaving the account (a negative amount).
+    Debit,
+    /// Money entering the account (a positive amount).
+    Credit,
+    /// The size of the amount, ignoring sign, is at least this value.
+    MinAmount(Decimal),
+    /// The size of the amount, ignoring sign, is at most this value.
+    MaxAmount(Decimal),
+    /// The transaction belongs to this account.
+    Account(AccountId),
+    /// The transaction was enriched with this merchant.
+    Merchant(MerchantId),
+    /// The transaction was enriched with this category.
+    ///
+    /// Matches the category itself rather than its personal finance group.
+    Category(CategoryId),
+    /// The description contains this text, ignoring case.
+    DescriptionContains(String),
+    /// Every filter matches. An empty list matches everything.
+    AllOf(Vec<Self>),
+    /// At least one filter matches. An empty list matches nothing.
+    AnyOf(Vec<Self>),
+}
+
+impl TransactionFilter {
+    /// Match transactions that satisfy every one of `filters`.
+    pub const fn all_of(filters: Vec<Self>) -> Self {
+        Self::AllOf(filters)
+    }
+
+    /// Match transactions that satisfy at least one of `filters`.
+    pub const fn any_of(filters: Vec<Self>) -> Self {
+        Self::AnyOf(filters)
+    }
+
+    /// Whether the transaction satisfies this filter.
+    pub fn matches(&self, transaction: &Transaction) -> bool {
+        match self {
+            Self::Debit => transaction.amount.is_sign_negative() && !transaction.amount.is_zero(),
+            Self::Credit => transaction.amount.is_sign_positive() && !transaction.amount.is_zero(),
+            Self::MinAmount(min) => transaction.amount.abs() >= *min,
+            Self::MaxAmount(max) => transaction.amount.abs() <= *max,
+            Self::Account(account) => transaction.account == *account,
+            Self::Merchant(merchant) => transaction
+                .enriched_data
+                .as_ref()
+                .is_some_and(|enriched| enriched.merchant.id == *merchant),
+            Self::Category(category) => transaction
+                .enriched_data
+                .as_ref()
+                .is_some_and(|enriched| enriched.category.id == *category),
+            Self::DescriptionContains(text) => transaction
+                .description
+                .to_lowercase()
+                .contains(&text.to_lowercase()),
+            Self::AllOf(filters) => filters.iter().all(|filter| filter.matches(transaction)),
+            Self::AnyOf(filters) => filters.iter().any(|filter| filter.matches(transaction)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{enriched_transaction_json, transaction_json};
+
+    const DATE: &str = "2025-01-01T00:00:00.000Z";
+
+    fn transaction(id: &str, amount: f64, merchant: Option<&str>) -> Transaction {
+        let json = match merchant {
+            Some(merchant) => enriched_transaction_json(id, DATE, amount, merchant),
+            None => transaction_json(id, DATE, amount),
+        };
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn transactions() -> Vec<Transaction> {
+        vec![
+            transaction("trans_small_debit", -20.0, None),
+            transaction("trans_large_debit", -150.0, None),
+            transaction("trans_large_credit", 500.0, None),
+            transaction("trans_merchant", -5.0, Some("X")),
+        ]
+    }
+
+    fn matching_ids(filter: &TransactionFilter) -> Vec<String> {
+        transactions()
+            .iter()
+            .filter(|transaction| filter.matches(transaction))
+            .map(|transaction| transaction.id.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_all_of() {
+        let filter = TransactionFilter::all_of(vec![
+            TransactionFilter::Debit,
+            TransactionFilter::MinAmount(Decimal::ONE_HUNDRED),
+        ]);
+
+        assert_eq!(matching_ids(&filter), ["trans_large_debit"]);
+    }
+
+    #[test]
+    fn test_any_of() {
+        let filter = TransactionFilter::any_of(vec![
+            TransactionFilter::all_of(vec![
+                TransactionFilter::Debit,
+                TransactionFilter::MinAmount(Decimal::ONE_HUNDRED),
+            ]),
+            TransactionFilter::Merchant(MerchantId::new("_merchant_x").unwrap()),
+        ]);
+
+        assert_eq!(
+            matching_ids(&filter),
+            ["trans_large_debit", "trans_merchant"]
+        );
+    }
+
+    #[test]
+    fn test_category() {
+        let filter = TransactionFilter::Category(CategoryId::new("cat_123").unwrap());
+        assert_eq!(matching_ids(&filter), ["trans_merchant"]);
+
+        let filter = TransactionFilter::Category(CategoryId::new("cat_other").unwrap());
+        assert!(matching_ids(&filter).is_empty());
+    }
+}
@@ -11,6 +11,8 @@
 //! - Type-safe API with strongly-typed models
 //! - Async/await support using tokio
 //! - Comprehensive error handling
+//! - Optional blocking client (`blocking` feature)
+//! - Optional request metrics via the `metrics` crate (`metrics` feature)
 //!
 //! ## Quick Start
 //!
@@ -50,16 +52,34 @@
 
 #![warn(missing_docs)]
 
+mod analysis;
 mod bank_account_number;
 mod client;
+mod date_range;
 mod error;
+mod export;
+mod filter;
 mod models;
+mod money;
 mod serde;
+#[cfg(test)]
+#[allow(
+    dead_code,
+    reason = "Not every fixture is used under every feature combination"
+)]
+mod test_utils;
 mod types;
 
+pub use analysis::*;
 pub use bank_account_number::*;
-pub use client::AkahuClient;
-pub use error::AkahuError;
+#[cfg(feature = "blocking")]
+pub use client::AkahuClientBlocking;
+pub use client::{AkahuApi, AkahuClient, Observer, ResponseCache, TransactionPages};
+pub use date_range::*;
+pub use error::{AkahuError, AkahuErrorKind};
+pub use export::write_transactions_json;
+pub use filter::*;
 pub use models::*;
+pub use money::*;
 pub(crate) use serde::*;
 pub use types::*;
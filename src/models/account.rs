@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{AccountId, AuthorizationId, BankAccountNumber};
+use crate::{AccountId, AuthorizationId, FormattedAccount, currency_code};
 
 /// An Akahu account is something that has a balance. Some connections (like
 /// banks) have lots of accounts, while others (like KiwiSaver providers) may
@@ -75,8 +75,10 @@ pub struct Account {
     /// the name will be the custom name (or nickname), e.g. "Spending Account".
     /// Otherwise Akahu falls back to the product name, e.g. "Super Saver".
     ///
+    /// The name is normalised when deserialized; see [`AccountName`].
+    ///
     /// [<https://developers.akahu.nz/docs/the-account-model#name>]
-    pub name: String,
+    pub name: AccountName,
 
     /// This attribute indicates the status of Akahu's connection to this account.
     ///
@@ -99,7 +101,11 @@ pub struct Account {
     ///
     /// [<https://developers.akahu.nz/docs/the-account-model#formatted_account>]
     // TODO: could hyave a strongly defined type here.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "formatted_account",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
     pub formatted_acount: Option<String>,
 
     /// Akahu can refresh different parts of an account's data at different rates.
@@ -109,7 +115,11 @@ pub struct Account {
     /// When looking at a timestamp in here, you can think "Akahu's view of the
     /// account (balance/metadata/transactions) is up to date as of $TIME".
     ///
+    /// Freshly connected accounts may omit this object, in which case every
+    /// timestamp is `None`.
+    ///
     /// [<https://developers.akahu.nz/docs/the-account-model#refreshed>]
+    #[serde(default)]
     pub refreshed: RefreshDetails,
 
     /// The account balance.
@@ -134,6 +144,262 @@ pub struct Account {
     pub attributes: Vec<Attribute>,
 }
 
+/// The accounts a user connected with a single login at their bank.
+///
+/// Akahu has no endpoint for listing authorisations, so these are built from the
+/// [`authorisation`](Account::authorisation) of each account. Revoking an
+/// authorisation removes access to all of its accounts at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Authorisation {
+    /// The authorisation identifier shared by the accounts.
+    pub id: AuthorizationId,
+    /// The accounts belonging to this authorisation, in the order they were given.
+    pub accounts: Vec<Account>,
+}
+
+impl Authorisation {
+    /// Group accounts by their authorisation.
+    ///
+    /// Authorisations are returned in the order their first account appears.
+    pub fn group<I: IntoIterator<Item = Account>>(accounts: I) -> Vec<Self> {
+        let mut authorisations: Vec<Self> = Vec::new();
+        for account in accounts {
+            match authorisations
+                .iter_mut()
+                .find(|authorisation| authorisation.id == account.authorisation)
+            {
+                Some(authorisation) => authorisation.accounts.push(account),
+                None => authorisations.push(Self {
+                    id: account.authorisation.clone(),
+                    accounts: vec![account],
+                }),
+            }
+        }
+        authorisations
+    }
+
+    /// Whether every account in this authorisation is inactive, meaning the
+    /// user needs to reconnect.
+    pub fn is_inactive(&self) -> bool {
+        self.accounts
+            .iter()
+            .all(|account| matches!(account.status, Active::Inactive))
+    }
+}
+
+/// The name of an account, with surrounding whitespace trimmed and internal
+/// runs of whitespace collapsed to a single space.
+///
+/// Some integrations pad names or send them with doubled spaces, which makes
+/// them awkward to display or compare. The value exactly as Akahu sent it is
+/// kept in [`raw`](Self::raw), and is what gets serialized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccountName {
+    /// The normalised name.
+    name: String,
+    /// The name as provided.
+    raw: String,
+}
+
+impl AccountName {
+    /// Create a name, normalising its whitespace.
+    pub fn new<T: Into<String>>(raw: T) -> Self {
+        let raw = raw.into();
+        Self {
+            name: raw.split_whitespace().collect::<Vec<_>>().join(" "),
+            raw,
+        }
+    }
+
+    /// The normalised name.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// The name exactly as provided, before normalisation.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Consume and get the normalised name.
+    pub fn into_inner(self) -> String {
+        self.name
+    }
+}
+
+impl std::fmt::Display for AccountName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl From<String> for AccountName {
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<&str> for AccountName {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl AsRef<str> for AccountName {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::ops::Deref for AccountName {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        &self.name
+    }
+}
+
+impl PartialEq<str> for AccountName {
+    fn eq(&self, other: &str) -> bool {
+        self.name == other
+    }
+}
+
+impl PartialEq<&str> for AccountName {
+    fn eq(&self, other: &&str) -> bool {
+        self.name == *other
+    }
+}
+
+impl Serialize for AccountName {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl AsRef<AccountId> for Account {
+    fn as_ref(&self) -> &AccountId {
+        &self.id
+    }
+}
+
+impl From<&Account> for AccountId {
+    fn from(account: &Account) -> Self {
+        account.id.clone()
+    }
+}
+
+impl<'a> From<&'a Account> for &'a AccountId {
+    fn from(account: &'a Account) -> Self {
+        &account.id
+    }
+}
+
+/// Page on my.akahu.nz where users can re-establish inactive connections.
+const MY_AKAHU_CONNECTIONS_URL: &str = "https://my.akahu.nz/connections";
+
+impl Account {
+    /// Where to send the user to reconnect this account, if it is
+    /// [inactive](Active::Inactive).
+    ///
+    /// Akahu recommends directing users with inactive accounts either back through
+    /// your OAuth flow or to [<https://my.akahu.nz/connections>]. This returns the
+    /// latter, since it works regardless of how your app authorises users.
+    ///
+    /// Returns `None` for active accounts.
+    pub fn reconnection_url(&self) -> Option<url::Url> {
+        match self.status {
+            Active::Inactive => url::Url::parse(MY_AKAHU_CONNECTIONS_URL).ok(),
+            Active::Active => None,
+        }
+    }
+
+    /// A name suitable for display, never empty.
+    ///
+    /// Akahu already falls back to the product name when an account has no
+    /// nickname, but some integrations provide neither. In that case this falls
+    /// back to the formatted account number, and then to the account type.
+    pub fn display_name(&self) -> &str {
+        if !self.name.is_empty() {
+            return self.name.as_str();
+        }
+        match self.formatted_acount.as_deref().map(str::trim) {
+            Some(formatted_account) if !formatted_account.is_empty() => formatted_account,
+            Some(_) | None => self.kind.as_str(),
+        }
+    }
+
+    /// Whether this account has the given [Attribute].
+    pub fn has_attribute(&self, attribute: &Attribute) -> bool {
+        self.attributes.contains(attribute)
+    }
+
+    /// Whether this account can initiate payments to another bank account.
+    ///
+    /// Use this to decide which accounts to offer as a payment source.
+    pub fn can_pay_from(&self) -> bool {
+        self.has_attribute(&Attribute::PaymentFrom)
+    }
+
+    /// Whether this account can receive payments from another bank account.
+    pub fn can_receive_payment(&self) -> bool {
+        self.has_attribute(&Attribute::PaymentTo)
+    }
+
+    /// Whether this account can receive transfers from accounts belonging to the
+    /// same set of credentials.
+    pub fn can_transfer_to(&self) -> bool {
+        self.has_attribute(&Attribute::TransferTo)
+    }
+
+    /// Whether this account can initiate transfers to accounts belonging to the
+    /// same set of credentials.
+    pub fn can_transfer_from(&self) -> bool {
+        self.has_attribute(&Attribute::TransferFrom)
+    }
+
+    /// Whether Akahu can fetch transactions for this account.
+    pub fn has_transactions(&self) -> bool {
+        self.has_attribute(&Attribute::Transactions)
+    }
+
+    /// The credit limit of a credit card account, if known.
+    const fn credit_card_limit(&self) -> Option<rust_decimal::Decimal> {
+        if matches!(self.kind, BankAccountKind::CreditCard) {
+            self.balance.limit
+        } else {
+            None
+        }
+    }
+
+    /// How much more can be spent on a credit card before reaching its limit.
+    ///
+    /// Credit card balances are negative when money is owed, so this is the limit
+    /// plus the current balance. A card in credit has more than its limit available.
+    ///
+    /// Returns `None` for accounts that aren't credit cards or have no known limit.
+    pub fn available_credit(&self) -> Option<rust_decimal::Decimal> {
+        self.credit_card_limit()?.checked_add(self.balance.current)
+    }
+
+    /// The percentage of a credit card's limit that is currently owed, e.g. `25`
+    /// for $500 owed on a $2,000 limit.
+    ///
+    /// A card in credit has a utilisation of `0`. Returns `None` for accounts that
+    /// aren't credit cards or have no known (non-zero) limit.
+    pub fn credit_utilisation(&self) -> Option<rust_decimal::Decimal> {
+        let limit = self.credit_card_limit()?;
+        let owed = self.balance.current.min(rust_decimal::Decimal::ZERO).abs();
+        owed.checked_div(limit)?
+            .checked_mul(rust_decimal::Decimal::ONE_HUNDRED)
+    }
+}
+
 /// This attribute indicates the status of Akahu's connection to this account.
 ///
 /// It is possible for Akahu to lose the ability to authenticate with a
@@ -142,7 +408,7 @@ pub struct Account {
 /// cases can cause our long-lived access to be revoked.
 ///
 /// [<https://developers.akahu.nz/docs/the-account-model#status>]
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Active {
     /// Akahu can authenticate with the institution to retrieve data
@@ -176,6 +442,8 @@ impl Active {
     }
 }
 
+crate::deserialize_case_insensitive!(Active, "an account status");
+
 impl std::str::FromStr for Active {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -257,8 +525,8 @@ pub struct AccountMetadata {
 pub struct PaymentDetails {
     /// The recipient's name.
     pub account_holder: String,
-    /// The recipient's NZ bank account number.
-    pub account_number: BankAccountNumber,
+    /// The recipient's account number, usually an NZ bank account number.
+    pub account_number: FormattedAccount,
     /// Details required to be in the payment particulars.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub particulars: Option<String>,
@@ -318,32 +586,205 @@ pub struct LoanDetails {
     pub repayment: Option<RepaymentDetails>,
 }
 
+impl LoanDetails {
+    /// Project the repayments due before `date`.
+    ///
+    /// Starting from the next repayment, repayments of the next instalment amount
+    /// are assumed to continue at the loan's frequency. Returns the number of
+    /// repayments falling strictly before `date` and their total.
+    ///
+    /// Returns `None` if there is no repayment information, no next repayment
+    /// date, the frequency is not recognised, or the total would overflow.
+    pub fn repayments_until(
+        &self,
+        date: chrono::DateTime<chrono::Utc>,
+    ) -> Option<(u32, rust_decimal::Decimal)> {
+        let repayment = self.repayment.as_ref()?;
+        let first = repayment.next_date?;
+
+        let mut count: u32 = 0;
+        while repayment.frequency.nth_date(first, count)? < date {
+            count = count.checked_add(1)?;
+        }
+
+        let total = repayment
+            .next_amount
+            .checked_mul(rust_decimal::Decimal::from(count))?;
+        Some((count, total))
+    }
+}
+
 /// Interest rate information for a loan.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct InterestDetails {
-    /// The rate of interest.
-    #[serde(with = "rust_decimal::serde::arbitrary_precision")]
-    pub rate: rust_decimal::Decimal,
+    /// The rate of interest, per annum.
+    pub rate: Rate,
     /// The type of interest rate (E.g. FIXED).
     // TODO: Could be an enum but we do not know all possible classifications.
     #[serde(rename = "type")]
     pub interest_type: String,
     /// When this interest rate expires, if available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// An annual interest rate.
+///
+/// Akahu reports rates in percent, so a wire value of `5.99` is 5.99% p.a. and
+/// `0.5` is 0.5% p.a. (not 50%). Use [`as_percentage`](Self::as_percentage) for
+/// display and [`as_fraction`](Self::as_fraction) for calculations, rather than
+/// the raw value, to avoid being out by a factor of 100.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Rate(#[serde(with = "rust_decimal::serde::arbitrary_precision")] rust_decimal::Decimal);
+
+impl Rate {
+    /// Create a rate from a percentage, e.g. `5.99` for 5.99%.
+    pub const fn from_percentage(percentage: rust_decimal::Decimal) -> Self {
+        Self(percentage)
+    }
+
+    /// Create a rate from a fraction, e.g. `0.0599` for 5.99%.
+    ///
+    /// Returns `None` if the percentage would overflow.
+    pub fn from_fraction(fraction: rust_decimal::Decimal) -> Option<Self> {
+        fraction
+            .checked_mul(rust_decimal::Decimal::ONE_HUNDRED)
+            .map(Self)
+    }
+
+    /// The rate as a percentage, e.g. `5.99` for 5.99%.
+    pub const fn as_percentage(&self) -> rust_decimal::Decimal {
+        self.0
+    }
+
+    /// The rate as a fraction, e.g. `0.0599` for 5.99%.
+    pub fn as_fraction(&self) -> rust_decimal::Decimal {
+        // Dividing by 100 only shifts the scale, so it cannot fail.
+        self.0
+            .checked_div(rust_decimal::Decimal::ONE_HUNDRED)
+            .unwrap_or_default()
+    }
+
+    /// Add two rates, returning `None` on overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Subtract `other` from this rate, returning `None` on overflow.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+}
+
+impl std::fmt::Display for Rate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}%", self.0.normalize())
+    }
+}
+
 /// Loan repayment information.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct RepaymentDetails {
     /// The frequency of the loan repayment (E.g. MONTHLY).
-    pub frequency: String,
+    pub frequency: RepaymentFrequency,
     /// The next repayment date, if available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub next_date: Option<chrono::DateTime<chrono::Utc>>,
     /// The next instalment amount.
     #[serde(with = "rust_decimal::serde::arbitrary_precision")]
     pub next_amount: rust_decimal::Decimal,
 }
 
+/// How often a loan is repaid.
+///
+/// Unrecognised frequencies are kept as provided.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum RepaymentFrequency {
+    /// Every week.
+    Weekly,
+    /// Every two weeks.
+    Fortnightly,
+    /// Every calendar month.
+    Monthly,
+    /// Every three calendar months.
+    Quarterly,
+    /// Every year.
+    Annually,
+    /// A frequency that could not be recognised, as provided by the institution.
+    Other(String),
+}
+
+impl RepaymentFrequency {
+    /// Get the repayment frequency as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Weekly => "WEEKLY",
+            Self::Fortnightly => "FORTNIGHTLY",
+            Self::Monthly => "MONTHLY",
+            Self::Quarterly => "QUARTERLY",
+            Self::Annually => "ANNUALLY",
+            Self::Other(value) => value,
+        }
+    }
+
+    /// The date `n` repayments after `first`, or `None` if the frequency is
+    /// not recognised or the date would overflow.
+    fn nth_date(
+        &self,
+        first: chrono::DateTime<chrono::Utc>,
+        n: u32,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        let add_days = |days: u32| {
+            first.checked_add_signed(chrono::Duration::days(i64::from(days.checked_mul(n)?)))
+        };
+        let add_months =
+            |months: u32| first.checked_add_months(chrono::Months::new(months.checked_mul(n)?));
+
+        match self {
+            Self::Weekly => add_days(7),
+            Self::Fortnightly => add_days(14),
+            Self::Monthly => add_months(1),
+            Self::Quarterly => add_months(3),
+            Self::Annually => add_months(12),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl From<String> for RepaymentFrequency {
+    fn from(value: String) -> Self {
+        match value.to_ascii_uppercase().as_str() {
+            "WEEKLY" => Self::Weekly,
+            "FORTNIGHTLY" => Self::Fortnightly,
+            "MONTHLY" => Self::Monthly,
+            "QUARTERLY" => Self::Quarterly,
+            "ANNUALLY" | "YEARLY" => Self::Annually,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl From<RepaymentFrequency> for String {
+    fn from(value: RepaymentFrequency) -> Self {
+        match value {
+            RepaymentFrequency::Other(value) => value,
+            known @ (RepaymentFrequency::Weekly
+            | RepaymentFrequency::Fortnightly
+            | RepaymentFrequency::Monthly
+            | RepaymentFrequency::Quarterly
+            | RepaymentFrequency::Annually) => known.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for RepaymentFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Akahu can refresh different parts of an account's data at different rates.
 /// The timestamps in the refreshed object tell you when that account data was
 /// last updated.
@@ -352,7 +793,7 @@ pub struct RepaymentDetails {
 /// account (balance/metadata/transactions) is up to date as of $TIME".
 ///
 /// [<https://developers.akahu.nz/docs/the-account-model#refreshed>]
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct RefreshDetails {
     /// When the balance was last updated.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -422,6 +863,13 @@ pub struct BalanceDetails {
 
     /// The [3 letter ISO 4217 currency code](https://www.xe.com/iso4217.php)
     /// that this balance is in (e.g. NZD).
+    ///
+    /// Codes are matched case-insensitively. Some non-bank accounts (e.g.
+    /// KiwiSaver) omit the currency entirely, in which case this defaults to NZD.
+    #[serde(
+        default = "currency_code::default_nzd",
+        deserialize_with = "currency_code::deserialize"
+    )]
     pub currency: iso_currency::Currency,
 }
 
@@ -429,7 +877,7 @@ pub struct BalanceDetails {
 /// and falls back to more general types for other types of connection.
 ///
 /// [<https://developers.akahu.nz/docs/the-account-model#type>]
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum BankAccountKind {
     /// An everyday spending account.
@@ -486,6 +934,8 @@ impl BankAccountKind {
     }
 }
 
+crate::deserialize_case_insensitive!(BankAccountKind, "an account type");
+
 impl std::str::FromStr for BankAccountKind {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -529,7 +979,7 @@ impl std::fmt::Display for BankAccountKind {
 /// The list of attributes indicates what abilities an account has.
 ///
 /// [<https://developers.akahu.nz/docs/the-account-model#attributes>]
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Attribute {
     /// Akahu can fetch available transactions from this account.
@@ -564,6 +1014,8 @@ impl Attribute {
     }
 }
 
+crate::deserialize_case_insensitive!(Attribute, "an account attribute");
+
 impl std::str::FromStr for Attribute {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -597,3 +1049,411 @@ impl std::fmt::Display for Attribute {
         write!(f, "{}", self.as_str())
     }
 }
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::test_utils::account_json;
+    use rust_decimal::Decimal;
+
+    fn account_with_attributes(attributes: &[&str]) -> Account {
+        let mut json = account_json("acc_123");
+        json.as_object_mut()
+            .unwrap()
+            .insert("attributes".to_string(), attributes.into());
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn account_named(name: &str) -> Account {
+        let mut json = account_json("acc_123");
+        let object = json.as_object_mut().unwrap();
+        object.insert("name".to_string(), name.into());
+        object.insert("type".to_string(), "SAVINGS".into());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_account_name_is_normalised() {
+        let account = account_named("  Bills \t  Account ");
+
+        assert_eq!(account.name, "Bills Account");
+        assert_eq!(account.name.raw(), "  Bills \t  Account ");
+        assert_eq!(account.display_name(), "Bills Account");
+        assert_eq!(
+            serde_json::to_value(&account.name).unwrap(),
+            "  Bills \t  Account ",
+            "the raw name is serialized so round trips are lossless"
+        );
+    }
+
+    #[test]
+    fn test_empty_account_name_falls_back() {
+        let account = account_named("   ");
+        assert!(account.name.is_empty());
+        assert_eq!(account.display_name(), "SAVINGS");
+
+        let mut account = account_named("");
+        account.formatted_acount = Some("12-3456-7890123-00".to_string());
+        assert_eq!(account.display_name(), "12-3456-7890123-00");
+    }
+
+    #[test]
+    fn test_null_and_absent_optional_fields() {
+        let absent: BalanceDetails =
+            serde_json::from_value(serde_json::json!({ "current": 100.5, "currency": "NZD" }))
+                .unwrap();
+        let null: BalanceDetails = serde_json::from_value(serde_json::json!({
+            "current": 100.5,
+            "currency": "NZD",
+            "available": null,
+            "limit": null,
+            "overdrawn": null
+        }))
+        .unwrap();
+
+        for balance in [absent, null] {
+            assert_eq!(balance.available, None);
+            assert_eq!(balance.limit, None);
+            assert_eq!(balance.overdrawn, None);
+        }
+
+        let absent: LoanDetails = serde_json::from_value(serde_json::json!({
+            "purpose": "HOME",
+            "type": "TABLE",
+            "repayment": { "frequency": "MONTHLY", "next_amount": 500 },
+            "interest": { "rate": 5.99, "type": "FIXED" }
+        }))
+        .unwrap();
+        let null: LoanDetails = serde_json::from_value(serde_json::json!({
+            "purpose": "HOME",
+            "type": "TABLE",
+            "repayment": { "frequency": "MONTHLY", "next_amount": 500, "next_date": null },
+            "interest": { "rate": 5.99, "type": "FIXED", "expires_at": null },
+            "initial_principal": null,
+            "matures_at": null
+        }))
+        .unwrap();
+
+        for loan in [absent, null] {
+            assert_eq!(loan.repayment.unwrap().next_date, None);
+            assert_eq!(loan.interest.unwrap().expires_at, None);
+            assert_eq!(loan.initial_principal, None);
+            assert_eq!(loan.matures_at, None);
+        }
+    }
+
+    fn account_in(id: &str, authorisation: &str, status: &str) -> Account {
+        let mut json = account_json(id);
+        let object = json.as_object_mut().unwrap();
+        object.insert("_authorisation".to_string(), authorisation.into());
+        object.insert("status".to_string(), status.into());
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_group_authorisations() {
+        let authorisations = Authorisation::group(vec![
+            account_in("acc_1", "auth_a", "ACTIVE"),
+            account_in("acc_2", "auth_b", "INACTIVE"),
+            account_in("acc_3", "auth_a", "INACTIVE"),
+        ]);
+
+        let grouped: Vec<(&str, Vec<&str>)> = authorisations
+            .iter()
+            .map(|authorisation| {
+                (
+                    authorisation.id.as_str(),
+                    authorisation
+                        .accounts
+                        .iter()
+                        .map(|account| account.id.as_str())
+                        .collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            grouped,
+            vec![
+                ("auth_a", vec!["acc_1", "acc_3"]),
+                ("auth_b", vec!["acc_2"])
+            ]
+        );
+
+        let inactive: Vec<bool> = authorisations
+            .iter()
+            .map(Authorisation::is_inactive)
+            .collect();
+        assert_eq!(inactive, vec![false, true]);
+    }
+
+    fn loan_with_repayment(frequency: &str, next_date: &str) -> LoanDetails {
+        serde_json::from_value(serde_json::json!({
+            "purpose": "HOME",
+            "type": "TABLE",
+            "repayment": {
+                "frequency": frequency,
+                "next_date": next_date,
+                "next_amount": 512.25
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_repayments_until_monthly() {
+        let loan = loan_with_repayment("MONTHLY", "2025-01-31T00:00:00Z");
+        assert_eq!(
+            loan.repayment.as_ref().unwrap().frequency,
+            RepaymentFrequency::Monthly
+        );
+
+        // Jan 31, Feb 28, Mar 31 and Apr 30 fall before May 1.
+        let (count, total) = loan
+            .repayments_until("2025-05-01T00:00:00Z".parse().unwrap())
+            .unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(total, rust_decimal::Decimal::new(204_900, 2));
+    }
+
+    #[test]
+    fn test_repayments_until_fortnightly() {
+        let loan = loan_with_repayment("FORTNIGHTLY", "2025-01-06T00:00:00Z");
+
+        // Every 14 days from Jan 6; Mar 31 itself is excluded.
+        let (count, total) = loan
+            .repayments_until("2025-03-31T00:00:00Z".parse().unwrap())
+            .unwrap();
+        assert_eq!(count, 6);
+        assert_eq!(total, rust_decimal::Decimal::new(307_350, 2));
+
+        let (count, _) = loan
+            .repayments_until("2025-01-01T00:00:00Z".parse().unwrap())
+            .unwrap();
+        assert_eq!(count, 0, "no repayments before the next one is due");
+    }
+
+    #[test]
+    fn test_repayments_until_unknown_frequency() {
+        let loan = loan_with_repayment("WHENEVER", "2025-01-06T00:00:00Z");
+
+        assert_eq!(
+            loan.repayment.as_ref().unwrap().frequency,
+            RepaymentFrequency::Other("WHENEVER".to_string())
+        );
+        assert_eq!(
+            loan.repayments_until("2025-03-31T00:00:00Z".parse().unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_enums_deserialize_case_insensitively() {
+        for value in ["creditcard", "CreditCard", "CREDITCARD"] {
+            let kind: BankAccountKind = serde_json::from_value(value.into()).unwrap();
+            assert_eq!(kind, BankAccountKind::CreditCard, "{value}");
+        }
+
+        let status: Active = serde_json::from_value("Inactive".into()).unwrap();
+        assert_eq!(status, Active::Inactive);
+
+        let attribute: Attribute = serde_json::from_value("payment_from".into()).unwrap();
+        assert_eq!(attribute, Attribute::PaymentFrom);
+
+        assert_eq!(
+            serde_json::to_value(BankAccountKind::CreditCard).unwrap(),
+            "CREDITCARD",
+            "serialization keeps the canonical casing"
+        );
+        serde_json::from_value::<BankAccountKind>("credit_card".into()).unwrap_err();
+    }
+
+    #[test]
+    fn test_interest_rate() {
+        let interest: InterestDetails = serde_json::from_value(serde_json::json!({
+            "rate": 5.99,
+            "type": "FIXED",
+            "expires_at": null
+        }))
+        .unwrap();
+
+        assert_eq!(interest.rate.as_percentage(), Decimal::new(599, 2));
+        assert_eq!(interest.rate.as_fraction(), Decimal::new(599, 4));
+        assert_eq!(interest.rate.to_string(), "5.99%");
+        assert_eq!(
+            serde_json::to_value(interest.rate).unwrap(),
+            serde_json::json!(5.99),
+            "the wire value is kept as-is"
+        );
+
+        let small = Rate::from_percentage(Decimal::new(5, 1));
+        assert_eq!(small.as_fraction(), Decimal::new(5, 3), "0.5% is not 50%");
+        assert_eq!(
+            Rate::from_fraction(Decimal::new(599, 4)).unwrap(),
+            interest.rate
+        );
+        assert_eq!(
+            interest.rate.checked_sub(small).unwrap().as_percentage(),
+            Decimal::new(549, 2)
+        );
+    }
+
+    fn balance_with_currency(currency: Option<&str>) -> BalanceDetails {
+        let balance = match currency {
+            Some(currency) => serde_json::json!({ "current": 100.5, "currency": currency }),
+            None => serde_json::json!({ "current": 100.5 }),
+        };
+        serde_json::from_value(balance).unwrap()
+    }
+
+    #[test]
+    fn test_balance_currency_standard() {
+        let balance = balance_with_currency(Some("NZD"));
+        assert_eq!(balance.currency, iso_currency::Currency::NZD);
+    }
+
+    #[test]
+    fn test_balance_currency_lowercase() {
+        let balance = balance_with_currency(Some("nzd"));
+        assert_eq!(balance.currency, iso_currency::Currency::NZD);
+    }
+
+    #[test]
+    fn test_balance_currency_missing_defaults_to_nzd() {
+        let balance = balance_with_currency(None);
+        assert_eq!(balance.currency, iso_currency::Currency::NZD);
+    }
+
+    #[test]
+    fn test_balance_currency_unknown_is_rejected() {
+        let result = serde_json::from_value::<BalanceDetails>(serde_json::json!({
+            "current": 100.5,
+            "currency": "XYZ",
+        }));
+        assert!(result.is_err(), "unknown currency codes should fail");
+    }
+
+    #[test]
+    fn test_capabilities_from_attributes() {
+        let account = account_with_attributes(&["PAYMENT_FROM", "TRANSACTIONS"]);
+
+        assert!(account.can_pay_from(), "PAYMENT_FROM should allow payments");
+        assert!(
+            account.has_transactions(),
+            "TRANSACTIONS should be reported"
+        );
+        assert!(!account.can_receive_payment(), "PAYMENT_TO was not listed");
+        assert!(!account.can_transfer_to(), "TRANSFER_TO was not listed");
+        assert!(!account.can_transfer_from(), "TRANSFER_FROM was not listed");
+    }
+
+    #[test]
+    fn test_capabilities_without_attributes() {
+        let account = account_with_attributes(&[]);
+
+        assert!(!account.can_pay_from(), "no attributes means no payments");
+        assert!(
+            !account.can_receive_payment(),
+            "no attributes means no payments"
+        );
+        assert!(
+            !account.can_transfer_to(),
+            "no attributes means no transfers"
+        );
+        assert!(
+            !account.can_transfer_from(),
+            "no attributes means no transfers"
+        );
+        assert!(
+            !account.has_transactions(),
+            "no attributes means no transactions"
+        );
+    }
+
+    fn credit_card(kind: &str, current: f64, limit: Option<f64>) -> Account {
+        let mut json = account_json("acc_123");
+        let object = json.as_object_mut().unwrap();
+        object.insert("type".to_string(), kind.into());
+        let balance = object
+            .get_mut("balance")
+            .and_then(serde_json::Value::as_object_mut)
+            .unwrap();
+        balance.insert("current".to_string(), current.into());
+        if let Some(limit) = limit {
+            balance.insert("limit".to_string(), limit.into());
+        }
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_credit_card_available_credit_and_utilisation() {
+        let account = credit_card("CREDITCARD", -500.0, Some(2000.0));
+
+        assert_eq!(
+            account.available_credit(),
+            Some(rust_decimal::Decimal::from(1500))
+        );
+        assert_eq!(
+            account.credit_utilisation(),
+            Some(rust_decimal::Decimal::from(25))
+        );
+    }
+
+    #[test]
+    fn test_credit_card_in_credit() {
+        let account = credit_card("CREDITCARD", 100.0, Some(2000.0));
+
+        assert_eq!(
+            account.available_credit(),
+            Some(rust_decimal::Decimal::from(2100))
+        );
+        assert_eq!(
+            account.credit_utilisation(),
+            Some(rust_decimal::Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_credit_helpers_require_credit_card_with_limit() {
+        let no_limit = credit_card("CREDITCARD", -500.0, None);
+        assert_eq!(no_limit.available_credit(), None);
+        assert_eq!(no_limit.credit_utilisation(), None);
+
+        let checking = credit_card("CHECKING", -500.0, Some(1000.0));
+        assert_eq!(
+            checking.available_credit(),
+            None,
+            "overdrafts are not credit cards"
+        );
+        assert_eq!(checking.credit_utilisation(), None);
+
+        let zero_limit = credit_card("CREDITCARD", -500.0, Some(0.0));
+        assert_eq!(zero_limit.credit_utilisation(), None);
+    }
+
+    #[test]
+    fn test_reconnection_url() {
+        let mut account = account_with_attributes(&[]);
+        assert_eq!(account.reconnection_url(), None, "active accounts are fine");
+
+        account.status = Active::Inactive;
+        assert_eq!(
+            account.reconnection_url().unwrap().as_str(),
+            "https://my.akahu.nz/connections"
+        );
+    }
+
+    #[test]
+    fn test_missing_refreshed_defaults_to_empty() {
+        let mut json = account_json("acc_123");
+        json.as_object_mut().unwrap().remove("refreshed");
+
+        let account: Account = serde_json::from_value(json).unwrap();
+
+        assert_eq!(account.refreshed, RefreshDetails::default());
+        assert_eq!(account.refreshed.balance, None);
+    }
+}
@@ -0,0 +1,11 @@
+//! Rust structs representing the categories Akahu uses to enrich transactions.
+//!
+//! [<https://developers.akahu.nz/docs/the-transaction-model#category>]
+
+use crate::TransactionCategory;
+
+/// An NZFCC category as returned by the categories endpoint.
+///
+/// Categories have the same shape as the category attached to an enriched
+/// [`Transaction`](crate::Transaction).
+pub type Category = TransactionCategory;
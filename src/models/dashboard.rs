@@ -0,0 +1,20 @@
+//! Types for fetching the data a typical dashboard shows in one call.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Account, PendingTransaction, Transaction};
+
+/// A user's accounts with their recent settled and pending transactions.
+///
+/// Returned by [`AkahuClient::get_dashboard`](crate::AkahuClient::get_dashboard).
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct DashboardSnapshot {
+    /// Every account the user has connected to your application.
+    pub accounts: Vec<Account>,
+    /// Settled transactions from the requested period, across all accounts.
+    pub transactions: Vec<Transaction>,
+    /// All pending transactions, across all accounts.
+    pub pending: Vec<PendingTransaction>,
+    /// When the snapshot was requested. The transaction period ends here.
+    pub fetched_at: chrono::DateTime<chrono::Utc>,
+}
@@ -4,7 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{BankAccountNumber, ConnectionId, space_separated_strings_as_vec};
+use super::Metadata;
+
+use crate::{
+    BankBranchId, ConnectionId, FormattedAccount, ProfileId, space_separated_strings_as_vec,
+};
 
 /// Status of an identity verification
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -72,12 +76,13 @@ pub struct Identity {
     /// Account holder's name
     pub name: String,
 
-    /// New Zealand bank account number in standard format (00-0000-0000000-00)
-    pub formatted_account: BankAccountNumber,
+    /// New Zealand bank account number in standard format (00-0000-0000000-00),
+    /// or a masked identifier if the institution hides part of it
+    pub formatted_account: FormattedAccount,
 
     /// Reserved metadata object
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub meta: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub meta: Metadata,
 }
 
 /// Address information from financial institution
@@ -186,9 +191,91 @@ pub struct AddressComponents {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub postal_code: Option<String>,
 
-    /// Country name
+    /// Country, parsed from a full name or 2-letter code where recognised
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub country: Option<String>,
+    pub country: Option<AddressCountry>,
+}
+
+/// Country of an address.
+///
+/// Institutions report countries inconsistently, as either a full name
+/// (`"New Zealand"`) or an ISO 3166-1 alpha-2 code (`"NZ"`). Recognised values
+/// are parsed into a [`Country`](iso_currency::Country); anything else is kept
+/// as-is. Recognised countries serialize as their full name.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum AddressCountry {
+    /// A recognised ISO 3166-1 country.
+    Iso(iso_currency::Country),
+    /// A country that could not be recognised, as provided by the institution.
+    Other(String),
+}
+
+impl AddressCountry {
+    /// Parse a country name or 2-letter code, keeping the raw value if it is not
+    /// recognised.
+    pub fn new<T: Into<String>>(value: T) -> Self {
+        let value = value.into();
+        let trimmed = value.trim();
+        let country = if trimmed.len() == 2 {
+            trimmed.to_ascii_uppercase().parse().ok()
+        } else {
+            iso_currency::Country::from_name(trimmed)
+        };
+        match country {
+            Some(country) => Self::Iso(country),
+            None => Self::Other(value),
+        }
+    }
+
+    /// The recognised country, if any.
+    pub const fn country(&self) -> Option<iso_currency::Country> {
+        match self {
+            Self::Iso(country) => Some(*country),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// The country's full name, or the raw value if it was not recognised.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Iso(country) => country.name(),
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<String> for AddressCountry {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for AddressCountry {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<iso_currency::Country> for AddressCountry {
+    fn from(value: iso_currency::Country) -> Self {
+        Self::Iso(value)
+    }
+}
+
+impl From<AddressCountry> for String {
+    fn from(value: AddressCountry) -> Self {
+        match value {
+            AddressCountry::Iso(country) => country.name().to_string(),
+            AddressCountry::Other(value) => value,
+        }
+    }
+}
+
+impl std::fmt::Display for AddressCountry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 /// Account information from identity verification
@@ -198,7 +285,7 @@ pub struct IdentityAccount {
     pub name: String,
 
     /// Account number in NZ format or masked identifier
-    pub account_number: BankAccountNumber,
+    pub account_number: FormattedAccount,
 
     /// Account holder name as displayed by the bank
     pub holder: String,
@@ -223,7 +310,7 @@ pub struct IdentityAccount {
 pub struct BranchInfo {
     /// Unique Akahu ID beginning with `bank_branch_`
     #[serde(rename = "_id")]
-    pub id: String,
+    pub id: BankBranchId,
 
     /// Descriptive name of the branch
     pub description: String,
@@ -250,7 +337,7 @@ pub struct IdentitySource {
 pub struct IdentityProfile {
     /// Profile ID beginning with `profile_`
     #[serde(rename = "_id")]
-    pub id: String,
+    pub id: ProfileId,
 }
 
 /// Request to verify a name
@@ -479,6 +566,155 @@ pub struct Party {
     pub tax_number: Option<String>,
 
     /// Additional metadata
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub meta: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub meta: Metadata,
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+
+    fn identity_account(account_number: &str) -> IdentityAccount {
+        serde_json::from_value(serde_json::json!({
+            "name": "Everyday",
+            "account_number": account_number,
+            "holder": "J SMITH",
+            "has_unlisted_holders": false,
+            "bank": "ASB",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_identity_account_full_number() {
+        let account = identity_account("12-3456-7890123-000");
+
+        let bank_account = account.account_number.bank_account().unwrap();
+        assert_eq!(bank_account.bank_code(), "12");
+        assert_eq!(bank_account.suffix(), "000");
+    }
+
+    #[test]
+    fn test_identity_account_masked_number() {
+        let account = identity_account("12-****-****123-00");
+
+        assert!(account.account_number.is_masked(), "digits are hidden");
+        assert_eq!(account.account_number.bank_account(), None);
+        assert_eq!(account.account_number.as_str(), "12-****-****123-00");
+        assert_eq!(
+            serde_json::to_value(&account)
+                .unwrap()
+                .get("account_number"),
+            Some(&serde_json::json!("12-****-****123-00"))
+        );
+    }
+
+    #[test]
+    fn test_party_meta_typed_getters() {
+        let party: Party = serde_json::from_value(serde_json::json!({
+            "_id": "party_123",
+            "name": "John Smith",
+            "meta": { "source": "ASB", "verified": true, "accounts": 2 },
+        }))
+        .unwrap();
+
+        assert_eq!(party.meta.get_string("source"), Some("ASB"));
+        assert_eq!(party.meta.get_bool("verified"), Some(true));
+        assert_eq!(party.meta.get_i64("accounts"), Some(2));
+        assert_eq!(party.meta.get_string("accounts"), None);
+        assert_eq!(party.meta.get_string("missing"), None);
+    }
+
+    #[test]
+    fn test_missing_or_null_meta_is_empty() {
+        let identity: Identity = serde_json::from_value(serde_json::json!({
+            "name": "John Smith",
+            "formatted_account": "12-3456-7890123-00",
+            "meta": null,
+        }))
+        .unwrap();
+        assert!(identity.meta.is_empty(), "null meta should be empty");
+
+        let party: Party = serde_json::from_value(serde_json::json!({
+            "_id": "party_123",
+            "name": "John Smith",
+        }))
+        .unwrap();
+        assert!(party.meta.is_empty(), "absent meta should be empty");
+        assert_eq!(serde_json::to_value(&party).unwrap().get("meta"), None);
+    }
+
+    fn address_country(country: &str) -> Option<AddressCountry> {
+        let components: AddressComponents =
+            serde_json::from_value(serde_json::json!({ "country": country })).unwrap();
+        components.country
+    }
+
+    #[test]
+    fn test_address_country_from_name() {
+        let country = address_country("New Zealand").unwrap();
+
+        assert_eq!(country.country(), Some(iso_currency::Country::NZ));
+        assert_eq!(country.as_str(), "New Zealand");
+    }
+
+    #[test]
+    fn test_address_country_from_code() {
+        let country = address_country("NZ").unwrap();
+        assert_eq!(country, AddressCountry::Iso(iso_currency::Country::NZ));
+        assert_eq!(
+            serde_json::to_value(&country).unwrap(),
+            serde_json::json!("New Zealand"),
+            "recognised countries serialize by name"
+        );
+
+        assert_eq!(
+            address_country("nz").unwrap().country(),
+            Some(iso_currency::Country::NZ)
+        );
+    }
+
+    #[test]
+    fn test_address_country_unrecognised() {
+        let country = address_country("Aotearoa").unwrap();
+
+        assert_eq!(country, AddressCountry::Other("Aotearoa".to_string()));
+        assert_eq!(country.country(), None);
+        assert_eq!(
+            serde_json::to_value(&country).unwrap(),
+            serde_json::json!("Aotearoa")
+        );
+    }
+
+    #[test]
+    fn test_typed_ids() {
+        let account: IdentityAccount = serde_json::from_value(serde_json::json!({
+            "name": "Everyday",
+            "account_number": "12-3456-7890123-00",
+            "holder": "J SMITH",
+            "has_unlisted_holders": false,
+            "bank": "ASB",
+            "branch": {
+                "_id": "bank_branch_123",
+                "description": "Auckland Central",
+            },
+        }))
+        .unwrap();
+        assert_eq!(
+            account.branch.unwrap().id,
+            BankBranchId::new("bank_branch_123").unwrap()
+        );
+
+        let profile: IdentityProfile =
+            serde_json::from_value(serde_json::json!({ "_id": "profile_123" })).unwrap();
+        assert_eq!(profile.id, ProfileId::new("profile_123").unwrap());
+
+        let source: IdentitySource =
+            serde_json::from_value(serde_json::json!({ "_id": "conn_123" })).unwrap();
+        assert_eq!(source.id, ConnectionId::new("conn_123").unwrap());
+    }
 }
@@ -0,0 +1,77 @@
+//! Loosely specified `meta` objects attached to some Akahu models.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+/// Free-form metadata object returned by Akahu.
+///
+/// Akahu reserves `meta` fields on several models without documenting their
+/// contents. This wraps the raw JSON object so the parent types are not tied to
+/// [`serde_json::Value`], and provides typed getters for reading known keys.
+/// A missing or `null` `meta` field deserializes to an empty object.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct Metadata(Map<String, Value>);
+
+impl Metadata {
+    /// Create an empty metadata object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the metadata object has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Get the raw JSON value for a key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    /// Get a key as a string, if present and a string.
+    pub fn get_string(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(Value::as_str)
+    }
+
+    /// Get a key as a boolean, if present and a boolean.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(Value::as_bool)
+    }
+
+    /// Get a key as an integer, if present and representable as an `i64`.
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key).and_then(Value::as_i64)
+    }
+
+    /// Get a key as a float, if present and a number.
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get(key).and_then(Value::as_f64)
+    }
+
+    /// Borrow the underlying JSON object.
+    pub const fn as_map(&self) -> &Map<String, Value> {
+        &self.0
+    }
+
+    /// Consume the metadata, returning the underlying JSON object.
+    pub fn into_inner(self) -> Map<String, Value> {
+        self.0
+    }
+}
+
+impl From<Map<String, Value>> for Metadata {
+    fn from(map: Map<String, Value>) -> Self {
+        Self(map)
+    }
+}
+
+impl<'de> Deserialize<'de> for Metadata {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Map<String, Value>>::deserialize(deserializer)
+            .map(|map| Self(map.unwrap_or_default()))
+    }
+}
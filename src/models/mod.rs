@@ -1,13 +1,29 @@
 //! Akahu API data models and response types.
 
 mod account;
+mod category;
+mod dashboard;
 mod identity;
 mod me;
+mod metadata;
+mod oauth;
+mod query;
+#[cfg(test)]
+mod round_trip;
+mod session;
 mod transaction;
+#[cfg(test)]
+mod wire_format;
 
 pub use account::*;
+pub use category::*;
+pub use dashboard::*;
 pub use identity::*;
 pub use me::*;
+pub use metadata::*;
+pub use oauth::*;
+pub use query::*;
+pub use session::*;
 pub use transaction::*;
 
 use serde::{Deserialize, Serialize};
@@ -27,6 +43,24 @@ pub struct ErrorResponse {
     pub message: String,
 }
 
+/// Error response carrying per-field validation errors.
+///
+/// Returned with a 422 status when a request body fails validation.
+#[derive(Debug, Deserialize)]
+pub struct ValidationErrorResponse {
+    /// The individual validation failures.
+    pub errors: Vec<FieldError>,
+}
+
+/// A validation failure for a single request field.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct FieldError {
+    /// The request field that failed validation.
+    pub field: String,
+    /// Why the field was rejected.
+    pub message: String,
+}
+
 /// Standard API response wrapper for a single item.
 ///
 /// Most Akahu API endpoints that return a single resource wrap the response
@@ -63,13 +97,18 @@ pub struct ItemResponse<T> {
 /// }
 /// ```
 ///
+/// A few legacy responses return `items` as a single object when there is only
+/// one result; this is read as a one-item list.
+///
 /// [<https://developers.akahu.nz/docs/response-formatting>]
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
 pub struct ListResponse<T> {
     /// Indicates if the request was successful.
     pub success: bool,
 
     /// The list of resources.
+    #[serde(deserialize_with = "crate::one_or_many::deserialize")]
     pub items: Vec<T>,
 }
 
@@ -108,3 +147,117 @@ pub struct CursorObject {
     /// Cursor value to use for fetching the next page of results.
     pub next: Option<Cursor>,
 }
+
+/// A deserialized value together with the JSON it was read from.
+///
+/// Useful for audit logging or forwarding the original response, and keeps any
+/// fields the typed model does not know about. Serializes as the raw JSON.
+///
+/// Any endpoint's response type can be wrapped, e.g. `ItemResponse<WithRaw<Account>>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithRaw<T> {
+    /// The typed value.
+    pub value: T,
+    /// The JSON the value was deserialized from.
+    pub raw: serde_json::Value,
+}
+
+impl<'de, T: serde::de::DeserializeOwned> Deserialize<'de> for WithRaw<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        let value = T::deserialize(&raw).map_err(serde::de::Error::custom)?;
+        Ok(Self { value, raw })
+    }
+}
+
+impl<T> Serialize for WithRaw<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.raw.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{account_json, transaction_json};
+
+    fn transaction_page(next: Option<&str>) -> PaginatedResponse<Transaction> {
+        serde_json::from_value(serde_json::json!({
+            "success": true,
+            "items": [
+                transaction_json("trans_1", "2025-01-01T10:00:00.000Z", -12.345),
+                transaction_json("trans_2", "2025-01-02T10:00:00.000Z", 1_000_000.1),
+            ],
+            "cursor": { "next": next },
+        }))
+        .unwrap()
+    }
+
+    fn round_trip(page: &PaginatedResponse<Transaction>) -> PaginatedResponse<Transaction> {
+        let json = serde_json::to_string(page).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_paginated_response_round_trip_with_cursor() {
+        let page = transaction_page(Some("next_cursor_abc"));
+
+        let restored = round_trip(&page);
+
+        assert_eq!(restored, page);
+        assert_eq!(restored.cursor.next, Some(Cursor::new("next_cursor_abc")));
+        assert_eq!(
+            restored.items.first().unwrap().amount.to_string(),
+            "-12.345",
+            "amounts should keep their exact decimal value"
+        );
+    }
+
+    #[test]
+    fn test_paginated_response_round_trip_without_cursor() {
+        let page = transaction_page(None);
+
+        let restored = round_trip(&page);
+
+        assert_eq!(restored, page);
+        assert_eq!(restored.cursor.next, None);
+    }
+
+    #[test]
+    fn test_list_response_accepts_single_object_or_array() {
+        let single: ListResponse<Account> = serde_json::from_str(
+            &serde_json::json!({
+                "success": true,
+                "items": account_json("acc_123"),
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let array: ListResponse<Account> = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "items": [account_json("acc_123")],
+        }))
+        .unwrap();
+
+        assert_eq!(single, array);
+        assert_eq!(single.items.len(), 1, "a single object is one item");
+        assert_eq!(
+            single.items.first().unwrap().balance.current.to_string(),
+            "100.5"
+        );
+    }
+
+    #[test]
+    fn test_list_response_still_rejects_other_shapes() {
+        let result = serde_json::from_value::<ListResponse<Account>>(serde_json::json!({
+            "success": true,
+            "items": "acc_123",
+        }));
+
+        assert!(result.is_err(), "a bare string is not a list of accounts");
+    }
+}
@@ -0,0 +1,97 @@
+//! Request and response types for the OAuth token exchange.
+//!
+//! [<https://developers.akahu.nz/docs/authorizing-with-oauth2>]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AppToken, AuthCode, ClientSecret, RedirectUri, UserToken};
+
+/// Body of the request exchanging an authorization code for a user access token.
+///
+/// The `Debug` output redacts the authorization code and client secret, so the
+/// request can be logged safely.
+///
+/// [<https://developers.akahu.nz/docs/authorizing-with-oauth2#exchanging-the-authorization-code>]
+#[derive(Serialize)]
+pub struct TokenExchangeRequest {
+    /// Always `authorization_code`.
+    pub grant_type: &'static str,
+    /// The authorization code received on the redirect.
+    pub code: AuthCode,
+    /// The redirect URI used to obtain the code.
+    pub redirect_uri: RedirectUri,
+    /// Your app ID token.
+    pub client_id: AppToken,
+    /// Your app secret.
+    pub client_secret: ClientSecret,
+}
+
+/// Placeholder printed in place of secret values.
+const REDACTED: &str = "<redacted>";
+
+impl std::fmt::Debug for TokenExchangeRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenExchangeRequest")
+            .field("grant_type", &self.grant_type)
+            .field("code", &REDACTED)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &REDACTED)
+            .finish()
+    }
+}
+
+/// Response to a successful token exchange.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct TokenExchangeResponse {
+    /// Indicates if the request was successful.
+    pub success: bool,
+
+    /// The user access token.
+    pub access_token: UserToken,
+
+    /// The token type, always `bearer`.
+    pub token_type: String,
+
+    /// Space-separated list of the scopes granted to the token.
+    #[serde(default)]
+    pub scope: String,
+}
+
+/// Error response from the OAuth endpoints, following the OAuth2 spec.
+#[derive(Debug, Deserialize)]
+pub struct OAuthErrorResponse {
+    /// OAuth error code (e.g., "invalid_grant")
+    pub error: String,
+    /// Optional human-readable error description
+    #[serde(default)]
+    pub error_description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_exchange_request_debug_is_redacted() {
+        let request = TokenExchangeRequest {
+            grant_type: "authorization_code",
+            code: AuthCode::new("code_very_secret"),
+            redirect_uri: RedirectUri::new("https://example.com/callback"),
+            client_id: AppToken::new("app_token_123"),
+            client_secret: ClientSecret::new("secret_very_secret"),
+        };
+
+        let debug = format!("{request:?}");
+
+        assert!(!debug.contains("code_very_secret"), "code leaked: {debug}");
+        assert!(
+            !debug.contains("secret_very_secret"),
+            "secret leaked: {debug}"
+        );
+        assert!(
+            debug.contains("app_token_123"),
+            "non-secret fields are shown"
+        );
+    }
+}
@@ -0,0 +1,306 @@
+//! Query parameters for filtering list endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Cursor, Transaction};
+
+/// Query string parameters for a list endpoint.
+///
+/// Implementors return their parameters as ordered key-value pairs, which the
+/// client encodes into the request URL. Unset parameters are omitted.
+pub trait QueryParams {
+    /// The query string pairs for these parameters.
+    fn to_pairs(&self) -> Vec<(&'static str, String)>;
+}
+
+impl<T: QueryParams + ?Sized> QueryParams for &T {
+    fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        (**self).to_pairs()
+    }
+}
+
+impl<T: QueryParams> QueryParams for Option<T> {
+    fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        self.as_ref().map(QueryParams::to_pairs).unwrap_or_default()
+    }
+}
+
+impl<A: QueryParams, B: QueryParams> QueryParams for (A, B) {
+    fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = self.0.to_pairs();
+        pairs.extend(self.1.to_pairs());
+        pairs
+    }
+}
+
+impl QueryParams for Cursor {
+    fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        vec![("cursor", self.to_string())]
+    }
+}
+
+/// Date bounds for the transaction list endpoints.
+///
+/// Akahu treats `start` as exclusive and `end` as inclusive, and all timestamps
+/// have millisecond resolution.
+///
+/// [<https://developers.akahu.nz/docs/accessing-transactional-data#query-parameters>]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionQueryParams {
+    /// Only return transactions after this timestamp (exclusive).
+    pub start: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only return transactions up to this timestamp (inclusive).
+    pub end: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TransactionQueryParams {
+    /// Query the entire range accessible to your app.
+    pub const fn new() -> Self {
+        Self {
+            start: None,
+            end: None,
+        }
+    }
+
+    /// Only return transactions after `start` (exclusive).
+    pub const fn start(mut self, start: chrono::DateTime<chrono::Utc>) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Only return transactions up to `end` (inclusive).
+    pub const fn end(mut self, end: chrono::DateTime<chrono::Utc>) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Return transactions from `timestamp` onwards, including any dated exactly
+    /// at `timestamp`.
+    ///
+    /// Because `start` is exclusive, passing the last seen transaction's date as
+    /// the next `start` skips any other transactions sharing that millisecond.
+    /// This moves `start` back by one millisecond so they are included.
+    ///
+    /// **Trade-off:** transactions dated exactly at `timestamp` that were already
+    /// fetched will be returned again, so callers should de-duplicate by
+    /// transaction ID. The overlap is limited to that single millisecond.
+    pub fn start_from_timestamp_inclusive(
+        mut self,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.start = Some(
+            timestamp
+                .checked_sub_signed(chrono::Duration::milliseconds(1))
+                .unwrap_or(timestamp),
+        );
+        self
+    }
+}
+
+impl QueryParams for TransactionQueryParams {
+    fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        [("start", self.start), ("end", self.end)]
+            .into_iter()
+            .filter_map(|(key, timestamp)| {
+                timestamp.map(|t| (key, t.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)))
+            })
+            .collect()
+    }
+}
+
+/// A page of settled transactions, together with the query that produced it.
+///
+/// Akahu cursors are only valid for the `start`/`end` bounds they were issued
+/// for. Rather than exposing the cursor, a page keeps it alongside its query so
+/// [`AkahuClient::get_next_transaction_page`](crate::AkahuClient::get_next_transaction_page)
+/// always requests the next page with the original bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionPage {
+    /// The transactions in this page.
+    pub items: Vec<Transaction>,
+    /// The query this page was fetched with.
+    query: TransactionQueryParams,
+    /// The cursor for the following page, if there is one.
+    next: Option<Cursor>,
+}
+
+impl TransactionPage {
+    /// Create a page from a query and the cursor returned with its items.
+    pub(crate) const fn new(
+        items: Vec<Transaction>,
+        query: TransactionQueryParams,
+        next: Option<Cursor>,
+    ) -> Self {
+        Self { items, query, next }
+    }
+
+    /// The query this page was fetched with.
+    pub const fn query(&self) -> &TransactionQueryParams {
+        &self.query
+    }
+
+    /// Whether there are more pages after this one.
+    pub const fn has_more(&self) -> bool {
+        self.next.is_some()
+    }
+
+    /// Get a cursor for the next page that can be persisted and resumed later with
+    /// [`AkahuClient::resume_transaction_page`](crate::AkahuClient::resume_transaction_page).
+    ///
+    /// Returns `None` if this is the last page.
+    pub fn resume_cursor(&self) -> Option<PaginationCursor> {
+        self.next.clone().map(|cursor| {
+            PaginationCursor::new(
+                PaginationCursor::TRANSACTIONS_ENDPOINT,
+                self.query.clone(),
+                cursor,
+            )
+        })
+    }
+
+    /// Split the page into the query and cursor needed to fetch the next page.
+    pub(crate) fn into_next(self) -> (TransactionQueryParams, Option<Cursor>) {
+        (self.query, self.next)
+    }
+}
+
+/// A resumable pagination position.
+///
+/// Unlike a bare [`Cursor`], this records the endpoint and query the cursor was
+/// issued for, so a persisted cursor can be checked before it is reused. Treat
+/// the serialized form as opaque.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaginationCursor {
+    /// The endpoint path the cursor belongs to, relative to the base URL.
+    endpoint: String,
+    /// The query the cursor was issued for.
+    query: TransactionQueryParams,
+    /// The raw Akahu cursor.
+    cursor: Cursor,
+}
+
+impl PaginationCursor {
+    /// Endpoint path for the settled transactions listing.
+    pub(crate) const TRANSACTIONS_ENDPOINT: &str = "transactions";
+
+    /// Create a resumable cursor.
+    pub(crate) fn new<T: Into<String>>(
+        endpoint: T,
+        query: TransactionQueryParams,
+        cursor: Cursor,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            query,
+            cursor,
+        }
+    }
+
+    /// The endpoint path the cursor belongs to.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// The query the cursor was issued for.
+    pub const fn query(&self) -> &TransactionQueryParams {
+        &self.query
+    }
+
+    /// Check the cursor belongs to `endpoint` and split it into its query and
+    /// raw cursor.
+    pub(crate) fn into_parts_for(
+        self,
+        endpoint: &str,
+    ) -> crate::error::AkahuResult<(TransactionQueryParams, Cursor)> {
+        if self.endpoint != endpoint {
+            return Err(crate::error::AkahuError::Pagination {
+                message: format!(
+                    "cursor was issued for '{}', not '{endpoint}'",
+                    self.endpoint
+                ),
+            });
+        }
+        Ok((self.query, self.cursor))
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_query_pairs() {
+        assert_eq!(TransactionQueryParams::new().to_pairs(), vec![]);
+
+        let query = TransactionQueryParams::new()
+            .start("2025-01-01T00:00:00Z".parse().unwrap())
+            .end("2025-01-31T23:59:59.999Z".parse().unwrap());
+        assert_eq!(
+            query.to_pairs(),
+            vec![
+                ("start", "2025-01-01T00:00:00.000Z".to_string()),
+                ("end", "2025-01-31T23:59:59.999Z".to_string()),
+            ]
+        );
+
+        let end_only = TransactionQueryParams::new().end("2025-01-31T00:00:00Z".parse().unwrap());
+        assert_eq!(
+            end_only.to_pairs(),
+            vec![("end", "2025-01-31T00:00:00.000Z".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_cursor_pairs_follow_query() {
+        let query = TransactionQueryParams::new().start("2025-01-01T00:00:00Z".parse().unwrap());
+        let cursor = Cursor::new("abc123");
+
+        assert_eq!(
+            (&query, Some(&cursor)).to_pairs(),
+            vec![
+                ("start", "2025-01-01T00:00:00.000Z".to_string()),
+                ("cursor", "abc123".to_string()),
+            ]
+        );
+        assert_eq!((&query, None::<&Cursor>).to_pairs().len(), 1);
+    }
+
+    #[test]
+    fn test_start_from_timestamp_inclusive() {
+        let timestamp: chrono::DateTime<chrono::Utc> = "2025-01-01T12:00:00.000Z".parse().unwrap();
+
+        let query = TransactionQueryParams::new().start_from_timestamp_inclusive(timestamp);
+
+        assert_eq!(
+            query.start,
+            Some("2025-01-01T11:59:59.999Z".parse().unwrap()),
+            "start should move back one millisecond"
+        );
+    }
+
+    #[test]
+    fn test_inclusive_start_overlap_is_one_millisecond() {
+        let timestamp: chrono::DateTime<chrono::Utc> = "2025-01-01T12:00:00.000Z".parse().unwrap();
+        let start = TransactionQueryParams::new()
+            .start_from_timestamp_inclusive(timestamp)
+            .start
+            .unwrap();
+
+        // With an exclusive start, the earliest timestamp that can be returned
+        // is one millisecond after `start`, which is exactly `timestamp`.
+        let earliest_returned = start + chrono::Duration::milliseconds(1);
+        assert_eq!(
+            earliest_returned, timestamp,
+            "the boundary is now inclusive"
+        );
+        assert_eq!(
+            timestamp.signed_duration_since(start),
+            chrono::Duration::milliseconds(1),
+            "overlap with the previous fetch is at most one millisecond"
+        );
+    }
+}
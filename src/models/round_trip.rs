@@ -0,0 +1,146 @@
+//! Property tests checking that models survive a serialize/deserialize round trip.
+//!
+//! Values are built from the JSON fixtures and then have their interesting
+//! fields replaced with generated ones: decimals of any scale (which rely on
+//! `arbitrary_precision`), timestamps, free text and optional flattened
+//! enrichment.
+
+#![allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+
+use proptest::prelude::*;
+use rust_decimal::Decimal;
+
+use crate::{
+    Account, AccountName, BankAccountKind, Transaction, TransactionKind,
+    test_utils::{account_json, enriched_transaction_json, transaction_json},
+};
+
+/// Any decimal representable from a 64-bit mantissa, at any scale.
+fn decimal() -> impl Strategy<Value = Decimal> {
+    (any::<i64>(), 0_u32..=28).prop_map(|(mantissa, scale)| Decimal::new(mantissa, scale))
+}
+
+/// A timestamp with millisecond precision between 1970 and 2100.
+fn timestamp() -> impl Strategy<Value = chrono::DateTime<chrono::Utc>> {
+    (0_i64..4_102_444_800_000)
+        .prop_map(|millis| chrono::DateTime::from_timestamp_millis(millis).unwrap())
+}
+
+fn transaction_kind() -> impl Strategy<Value = TransactionKind> {
+    prop::sample::select(vec![
+        TransactionKind::Credit,
+        TransactionKind::Debit,
+        TransactionKind::Payment,
+        TransactionKind::Transfer,
+        TransactionKind::StandingOrder,
+        TransactionKind::Eftpos,
+        TransactionKind::Interest,
+        TransactionKind::Fee,
+        TransactionKind::Tax,
+        TransactionKind::CreditCard,
+        TransactionKind::DirectDebit,
+        TransactionKind::DirectCredit,
+        TransactionKind::Atm,
+        TransactionKind::Loan,
+    ])
+}
+
+fn transaction() -> impl Strategy<Value = Transaction> {
+    (
+        "[a-z0-9]{1,24}",
+        timestamp(),
+        timestamp(),
+        "\\PC*",
+        decimal(),
+        proptest::option::of(decimal()),
+        transaction_kind(),
+        proptest::option::of(("\\PC*", proptest::option::of("[a-z]{1,12}"))),
+    )
+        .prop_map(
+            |(id, created_at, date, description, amount, balance, kind, enrichment)| {
+                let id = format!("trans_{id}");
+                let json = match enrichment {
+                    Some((merchant_name, domain)) => {
+                        let mut json = enriched_transaction_json(
+                            &id,
+                            "2025-01-01T00:00:00Z",
+                            0.0,
+                            &merchant_name,
+                        );
+                        *json.pointer_mut("/merchant/website").unwrap() = domain
+                            .map(|domain| format!("https://{domain}.co.nz/"))
+                            .into();
+                        json
+                    }
+                    None => transaction_json(&id, "2025-01-01T00:00:00Z", 0.0),
+                };
+
+                let mut transaction = Transaction::from_value_strict(json).unwrap();
+                transaction.created_at = created_at;
+                transaction.date = date;
+                transaction.description = description;
+                transaction.amount = amount;
+                transaction.balance = balance;
+                transaction.kind = kind;
+                transaction
+            },
+        )
+}
+
+fn account_kind() -> impl Strategy<Value = BankAccountKind> {
+    prop::sample::select(vec![
+        BankAccountKind::Checking,
+        BankAccountKind::Savings,
+        BankAccountKind::CreditCard,
+        BankAccountKind::Loan,
+        BankAccountKind::Kiwisaver,
+        BankAccountKind::Investment,
+        BankAccountKind::TermDeposit,
+        BankAccountKind::Foreign,
+        BankAccountKind::Tax,
+        BankAccountKind::Rewards,
+        BankAccountKind::Wallet,
+    ])
+}
+
+fn account() -> impl Strategy<Value = Account> {
+    (
+        "[a-z0-9]{1,24}",
+        "\\PC*",
+        decimal(),
+        proptest::option::of(decimal()),
+        proptest::option::of(decimal()),
+        account_kind(),
+        proptest::option::of(timestamp()),
+    )
+        .prop_map(|(id, name, current, available, limit, kind, refreshed)| {
+            let mut account: Account =
+                serde_json::from_value(account_json(&format!("acc_{id}"))).unwrap();
+            account.name = AccountName::new(name);
+            account.balance.current = current;
+            account.balance.available = available;
+            account.balance.limit = limit;
+            account.kind = kind;
+            account.refreshed.balance = refreshed;
+            account
+        })
+}
+
+proptest! {
+    #[test]
+    fn test_transaction_round_trip(transaction in transaction()) {
+        let json = serde_json::to_string(&transaction).unwrap();
+        let parsed: Transaction = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(parsed, transaction);
+    }
+
+    #[test]
+    fn test_account_round_trip(account in account()) {
+        let json = serde_json::to_string(&account).unwrap();
+        let parsed: Account = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(parsed, account);
+    }
+}
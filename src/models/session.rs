@@ -0,0 +1,240 @@
+//! Types describing an authorized user session.
+//!
+//! Akahu returns the granted scopes alongside the access token during the
+//! [OAuth token exchange](https://developers.akahu.nz/docs/authorizing-with-oauth2).
+//! These types let applications store that metadata with the token and check
+//! permissions before making a call that would otherwise be rejected.
+
+use serde::{Deserialize, Serialize};
+
+use crate::UserToken;
+
+/// A permission granted to your application by the user.
+///
+/// [<https://developers.akahu.nz/docs/authorizing-with-oauth2>]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Scope {
+    /// Long-lived access to the user's data, the default for OAuth apps.
+    EnduringConsent,
+    /// Short-lived access for a single interaction.
+    #[serde(rename = "ONEOFF")]
+    OneOff,
+    /// Access to the user's Akahu profile, such as their email address.
+    Akahu,
+    /// Access to the user's accounts and balances.
+    Accounts,
+    /// Access to the user's transactions.
+    Transactions,
+    /// Access to identity data about the account holder.
+    Identity,
+    /// Permission to initiate payments to other bank accounts.
+    Payments,
+    /// Permission to initiate transfers between the user's accounts.
+    Transfers,
+}
+
+impl Scope {
+    /// Get the scope as a string slice.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::EnduringConsent => "ENDURING_CONSENT",
+            Self::OneOff => "ONEOFF",
+            Self::Akahu => "AKAHU",
+            Self::Accounts => "ACCOUNTS",
+            Self::Transactions => "TRANSACTIONS",
+            Self::Identity => "IDENTITY",
+            Self::Payments => "PAYMENTS",
+            Self::Transfers => "TRANSFERS",
+        }
+    }
+
+    /// Get the scope as bytes.
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl std::str::FromStr for Scope {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ENDURING_CONSENT" => Ok(Self::EnduringConsent),
+            "ONEOFF" => Ok(Self::OneOff),
+            "AKAHU" => Ok(Self::Akahu),
+            "ACCOUNTS" => Ok(Self::Accounts),
+            "TRANSACTIONS" => Ok(Self::Transactions),
+            "IDENTITY" => Ok(Self::Identity),
+            "PAYMENTS" => Ok(Self::Payments),
+            "TRANSFERS" => Ok(Self::Transfers),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::convert::TryFrom<String> for Scope {
+    type Error = ();
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for Scope {
+    type Error = ();
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A user access token together with the scopes it was granted.
+///
+/// Dereferences to [`UserToken`], so a session can be passed anywhere the
+/// client expects a `&UserToken`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct AuthorizedSession {
+    /// The user's access token.
+    pub token: UserToken,
+
+    /// The scopes granted to this token.
+    pub scopes: Vec<Scope>,
+
+    /// When the token was granted.
+    pub granted_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl AuthorizedSession {
+    /// Create a session for a token that was granted just now.
+    pub fn new(token: UserToken, scopes: Vec<Scope>) -> Self {
+        Self {
+            token,
+            scopes,
+            granted_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Create a session from a space-separated scope string, as returned in the
+    /// `scope` field of the OAuth token response.
+    ///
+    /// Unrecognised scopes are ignored.
+    pub fn from_scope_string(token: UserToken, scope: &str) -> Self {
+        let scopes = scope
+            .split_whitespace()
+            .filter_map(|scope| scope.parse().ok())
+            .collect();
+        Self::new(token, scopes)
+    }
+
+    /// Whether the session was granted the given scope.
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    /// Check that the session was granted the given scope before making a call
+    /// that needs it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AkahuError::MissingScope`](crate::AkahuError::MissingScope) if
+    /// the scope was not granted.
+    pub fn require_scope(&self, scope: Scope) -> crate::error::AkahuResult<()> {
+        if self.has_scope(scope) {
+            Ok(())
+        } else {
+            Err(crate::error::AkahuError::MissingScope(scope))
+        }
+    }
+}
+
+impl std::ops::Deref for AuthorizedSession {
+    type Target = UserToken;
+    fn deref(&self) -> &Self::Target {
+        &self.token
+    }
+}
+
+impl AsRef<UserToken> for AuthorizedSession {
+    fn as_ref(&self) -> &UserToken {
+        &self.token
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::{AkahuError, AkahuResult},
+        test_utils::{mock_client, user_token},
+    };
+    use wiremock::{Mock, MockServer, ResponseTemplate, matchers::method};
+
+    async fn fetch_transactions(
+        client: &crate::AkahuClient,
+        session: &AuthorizedSession,
+    ) -> AkahuResult<usize> {
+        session.require_scope(Scope::Transactions)?;
+        let page = client.get_transactions(session, None, None, None).await?;
+        Ok(page.items.len())
+    }
+
+    #[test]
+    fn test_from_scope_string() {
+        let session =
+            AuthorizedSession::from_scope_string(user_token(), "ENDURING_CONSENT PAYMENTS BOGUS");
+
+        assert_eq!(session.scopes, [Scope::EnduringConsent, Scope::Payments]);
+        assert!(session.has_scope(Scope::Payments), "PAYMENTS was granted");
+        assert!(
+            !session.has_scope(Scope::Transfers),
+            "TRANSFERS was not granted"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_scope_short_circuits_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let session = AuthorizedSession::new(user_token(), vec![Scope::EnduringConsent]);
+        let result = fetch_transactions(&mock_client(&server), &session).await;
+
+        assert!(
+            matches!(result, Err(AkahuError::MissingScope(Scope::Transactions))),
+            "expected MissingScope, got {result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_session_derefs_to_user_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "items": [],
+                "cursor": { "next": null }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let session = AuthorizedSession::new(user_token(), vec![Scope::Transactions]);
+        let count = fetch_transactions(&mock_client(&server), &session)
+            .await
+            .unwrap();
+
+        assert_eq!(count, 0, "no transactions were returned");
+    }
+}
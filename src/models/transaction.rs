@@ -4,7 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{AccountId, BankAccountNumber, CategoryId, ConnectionId, MerchantId, TransactionId};
+use crate::{
+    AccountId, CategoryId, ConnectionId, FormattedAccount, MerchantId, TransactionId, via_owned_str,
+};
 
 /// A transaction is a record of money moving between two accounts. Akahu can
 /// provide transaction data from connected accounts for all bank integrations
@@ -54,6 +56,11 @@ pub struct Transaction {
     /// transaction.
     pub created_at: chrono::DateTime<chrono::Utc>,
 
+    /// The time that Akahu last updated this transaction, e.g. when it was
+    /// enriched. Not sent by every integration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+
     /// The date that the transaction was posted with the account holder, as an
     /// ISO 8601 timestamp. In many cases this will only be accurate to the day,
     /// due to the level of detail provided by the bank.
@@ -88,15 +95,77 @@ pub struct Transaction {
     /// additional permissions to view this data.
     ///
     /// [<https://developers.akahu.nz/docs/the-transaction-model#enriched-transaction-data>]
+    ///
+    /// **Note:** Enrichment fields are flattened into the top level of the
+    /// transaction. If they are present but fail to deserialize (for example an
+    /// unknown NZFCC code, or a category without a merchant), this field is
+    /// silently set to `None`. Use [`Transaction::from_value_strict`] to surface
+    /// those errors instead.
     #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
     pub enriched_data: Option<EnrichedTransactionData>,
 }
 
+impl Transaction {
+    /// Check that the timestamps are consistent, i.e. the transaction was not
+    /// updated before Akahu first saw it.
+    ///
+    /// A violation points to an upstream data issue rather than anything the
+    /// caller did, so it is logged as a warning and `false` is returned rather
+    /// than an error.
+    pub fn validate_timestamps(&self) -> bool {
+        let consistent = self
+            .updated_at
+            .is_none_or(|updated_at| updated_at >= self.created_at);
+        if !consistent {
+            tracing::warn!(
+                transaction = %self.id,
+                created_at = %self.created_at,
+                updated_at = ?self.updated_at,
+                "transaction was updated before it was created"
+            );
+        }
+        consistent
+    }
+
+    /// The top-level keys that make up [`EnrichedTransactionData`].
+    const ENRICHMENT_KEYS: [&'static str; 2] = ["category", "merchant"];
+
+    /// Deserialize a transaction, failing if enrichment data is present but invalid.
+    ///
+    /// The default `Deserialize` implementation is lenient: enrichment that cannot
+    /// be parsed is dropped rather than failing the whole transaction, which keeps
+    /// listings working when Akahu extends its categories but can mask shape
+    /// changes. This reads the enrichment keys explicitly and returns the
+    /// underlying error instead.
+    pub fn from_value_strict(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        let enrichment: serde_json::Map<String, serde_json::Value> = Self::ENRICHMENT_KEYS
+            .iter()
+            .filter_map(|key| {
+                value
+                    .get(key)
+                    .map(|field| ((*key).to_string(), field.clone()))
+            })
+            .collect();
+
+        let transaction: Self = serde_json::from_value(value)?;
+
+        if transaction.enriched_data.is_none() && !enrichment.is_empty() {
+            // Re-run the enrichment deserialization on its own to recover the error
+            // that the flattened `Option` swallowed.
+            serde_json::from_value::<EnrichedTransactionData>(serde_json::Value::Object(
+                enrichment,
+            ))?;
+        }
+
+        Ok(transaction)
+    }
+}
+
 /// What sort of transaction this is. Akahu tries to find a specific transaction
 /// type, falling back to "CREDIT" or "DEBIT" if nothing else is available.
 ///
 /// [<https://developers.akahu.nz/docs/the-transaction-model#type>]
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Hash)]
 pub enum TransactionKind {
     /// Money has entered the account.
     #[serde(rename = "CREDIT")]
@@ -167,6 +236,24 @@ impl TransactionKind {
     pub const fn as_bytes(&self) -> &'static [u8] {
         self.as_str().as_bytes()
     }
+
+    /// Get the high-level group this kind of transaction belongs to.
+    pub const fn group(&self) -> TransactionGroup {
+        match self {
+            Self::Debit
+            | Self::Payment
+            | Self::StandingOrder
+            | Self::Eftpos
+            | Self::CreditCard
+            | Self::DirectDebit
+            | Self::Atm => TransactionGroup::Spending,
+            Self::Credit | Self::DirectCredit => TransactionGroup::Income,
+            Self::Transfer => TransactionGroup::Transfer,
+            Self::Fee => TransactionGroup::Fee,
+            Self::Interest => TransactionGroup::Interest,
+            Self::Tax | Self::Loan => TransactionGroup::Other,
+        }
+    }
 }
 
 impl std::str::FromStr for TransactionKind {
@@ -212,6 +299,43 @@ impl std::fmt::Display for TransactionKind {
     }
 }
 
+/// A coarse grouping of [`TransactionKind`]s, for dashboards and summaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionGroup {
+    /// Money spent, e.g. EFTPOS, debits and payments.
+    Spending,
+    /// Money received, e.g. credits and direct credits.
+    Income,
+    /// Money moved between the user's own accounts.
+    Transfer,
+    /// Fees charged by the account provider.
+    Fee,
+    /// Interest paid by the account provider.
+    Interest,
+    /// Anything else, e.g. tax and loan payments.
+    Other,
+}
+
+impl TransactionGroup {
+    /// Get the transaction group as a string slice.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spending => "SPENDING",
+            Self::Income => "INCOME",
+            Self::Transfer => "TRANSFER",
+            Self::Fee => "FEE",
+            Self::Interest => "INTEREST",
+            Self::Other => "OTHER",
+        }
+    }
+}
+
+impl std::fmt::Display for TransactionGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// This is data added by the Akahu enrichment engine. You must have additional
 /// permissions to view this data.
 ///
@@ -233,6 +357,7 @@ pub struct TransactionCategory {
     #[serde(rename = "_id")]
     pub id: CategoryId,
     /// NZFCC category code
+    #[serde(deserialize_with = "via_owned_str::deserialize")]
     pub name: nzfcc::NzfccCode,
     /// Category groupings
     pub groups: TransactionGroups,
@@ -255,6 +380,7 @@ pub struct PersonalFinanceGroup {
     #[serde(rename = "_id")]
     pub id: CategoryId,
     /// Category group name
+    #[serde(deserialize_with = "via_owned_str::deserialize")]
     pub name: nzfcc::CategoryGroup,
 }
 
@@ -278,6 +404,18 @@ pub struct TransactionMerchant {
     pub website: Option<url::Url>,
 }
 
+impl TransactionMerchant {
+    /// The merchant's website host with any leading `www.` removed, e.g.
+    /// `thewarehouse.co.nz`.
+    ///
+    /// Useful for grouping transactions by merchant domain. Returns `None` if
+    /// there is no website or it has no host.
+    pub fn domain(&self) -> Option<&str> {
+        let host = self.website.as_ref()?.host_str()?;
+        Some(host.strip_prefix("www.").unwrap_or(host))
+    }
+}
+
 /// This is other metadata that we extract from the transaction, including the
 /// following fields (where possible).
 ///
@@ -296,9 +434,13 @@ pub struct TransactionMeta {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
 
-    /// The formatted NZ bank account number of the other party to this transaction.
+    /// The account number of the other party to this transaction, usually a
+    /// formatted NZ bank account number.
+    ///
+    /// Counterparty numbers come from the other bank and are not always valid NZ
+    /// account numbers, so these are classified rather than rejected.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub other_account: Option<BankAccountNumber>,
+    pub other_account: Option<FormattedAccount>,
 
     /// If this transaction was made in another currency, details about the currency conversion.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -328,6 +470,25 @@ pub struct TransactionConversion {
     pub rate: rust_decimal::Decimal,
 }
 
+impl TransactionConversion {
+    /// Whether the foreign amount converted at [`rate`](Self::rate) is within
+    /// `tolerance` of the transaction's NZD amount.
+    ///
+    /// Amounts are compared by magnitude, since the NZD amount is negative for
+    /// debits. Use this to flag enrichment data whose conversion looks suspect.
+    pub fn is_rate_consistent(
+        &self,
+        nzd_amount: rust_decimal::Decimal,
+        tolerance: rust_decimal::Decimal,
+    ) -> bool {
+        self.amount
+            .abs()
+            .checked_mul(self.rate)
+            .and_then(|converted| converted.checked_sub(nzd_amount.abs()))
+            .is_some_and(|difference| difference.abs() <= tolerance)
+    }
+}
+
 /// A pending transaction that has not yet been settled.
 ///
 /// Pending transactions are not stable - the date or description may change due to
@@ -367,3 +528,207 @@ pub struct PendingTransaction {
     #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<TransactionMeta>,
 }
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_timestamps() {
+        let mut transaction: Transaction =
+            serde_json::from_value(enriched_transaction_json()).unwrap();
+        assert_eq!(
+            transaction.updated_at,
+            Some("2025-01-01T00:00:00Z".parse().unwrap())
+        );
+        assert!(transaction.validate_timestamps());
+
+        transaction.updated_at = None;
+        assert!(
+            transaction.validate_timestamps(),
+            "a missing updated_at is not a violation"
+        );
+
+        transaction.updated_at = Some("2024-12-31T23:59:59Z".parse().unwrap());
+        assert!(!transaction.validate_timestamps());
+    }
+
+    fn enriched_transaction_json() -> serde_json::Value {
+        let mut json = crate::test_utils::enriched_transaction_json(
+            "trans_123",
+            "2025-01-01T00:00:00.000Z",
+            -12.5,
+            "The Warehouse",
+        );
+        *json.pointer_mut("/merchant/website").unwrap() = "https://www.thewarehouse.co.nz/".into();
+        let object = json.as_object_mut().unwrap();
+        object.insert("_user".to_string(), "user_123".into());
+        object.insert("updated_at".to_string(), "2025-01-01T00:00:00.000Z".into());
+        object.insert("hash".to_string(), "abc123".into());
+        json
+    }
+
+    #[test]
+    fn test_enriched_transaction_ignores_unknown_top_level_fields() {
+        let transaction = Transaction::from_value_strict(enriched_transaction_json()).unwrap();
+
+        let enriched = transaction.enriched_data.unwrap();
+        assert_eq!(enriched.merchant.name, "The Warehouse");
+        assert!(
+            enriched
+                .category
+                .groups
+                .other_groups
+                .unwrap_or_default()
+                .is_empty(),
+            "top-level fields must not leak into the category groups"
+        );
+    }
+
+    #[test]
+    fn test_unenriched_transaction() {
+        let mut json = enriched_transaction_json();
+        let object = json.as_object_mut().unwrap();
+        object.remove("category");
+        object.remove("merchant");
+
+        let lenient: Transaction = serde_json::from_value(json.clone()).unwrap();
+        let strict = Transaction::from_value_strict(json).unwrap();
+
+        assert_eq!(lenient.enriched_data, None);
+        assert_eq!(strict, lenient);
+    }
+
+    #[test]
+    fn test_invalid_enrichment_is_only_rejected_in_strict_mode() {
+        let mut json = enriched_transaction_json();
+        json.as_object_mut().unwrap().remove("merchant");
+
+        let lenient: Transaction = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(lenient.enriched_data, None);
+
+        Transaction::from_value_strict(json).unwrap_err();
+    }
+
+    fn pending_transfer_json(other_account: &str) -> serde_json::Value {
+        let mut json =
+            crate::test_utils::pending_transaction_json("2025-01-01T00:00:00.000Z", -45.2);
+        let object = json.as_object_mut().unwrap();
+        object.insert("type".to_string(), "TRANSFER".into());
+        object.insert("other_account".to_string(), other_account.into());
+        json
+    }
+
+    #[test]
+    fn test_unrecognised_other_account_does_not_fail_page() {
+        let page: crate::ListResponse<PendingTransaction> =
+            serde_json::from_value(serde_json::json!({
+                "success": true,
+                "items": [
+                    pending_transfer_json("99-1234-1234567-000"),
+                    pending_transfer_json("12-3456-7890123-001")
+                ]
+            }))
+            .unwrap();
+
+        let other_accounts: Vec<&FormattedAccount> = page
+            .items
+            .iter()
+            .filter_map(|pending| pending.meta.as_ref()?.other_account.as_ref())
+            .collect();
+        assert_eq!(
+            other_accounts,
+            [
+                &FormattedAccount::Other("99-1234-1234567-000".to_string()),
+                &FormattedAccount::new("12-3456-7890123-001"),
+            ]
+        );
+        assert!(
+            other_accounts
+                .get(1)
+                .and_then(|account| account.bank_account())
+                .is_some(),
+            "a valid counterparty number is still parsed"
+        );
+    }
+
+    fn merchant_with_website(website: Option<&str>) -> TransactionMerchant {
+        TransactionMerchant {
+            id: MerchantId::new("_merchant_123").unwrap(),
+            name: "The Warehouse".to_string(),
+            website: website.map(|website| website.parse().unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_merchant_domain() {
+        let merchant = merchant_with_website(Some("https://www.thewarehouse.co.nz/"));
+        assert_eq!(merchant.domain(), Some("thewarehouse.co.nz"));
+
+        let merchant = merchant_with_website(Some("https://shop.example.co.nz/path"));
+        assert_eq!(merchant.domain(), Some("shop.example.co.nz"));
+    }
+
+    #[test]
+    fn test_merchant_domain_without_host() {
+        let merchant = merchant_with_website(Some("mailto:hello@thewarehouse.co.nz"));
+        assert_eq!(merchant.domain(), None);
+
+        let merchant = merchant_with_website(None);
+        assert_eq!(merchant.domain(), None);
+    }
+
+    #[test]
+    fn test_transaction_kind_group() {
+        let cases = [
+            (TransactionKind::Credit, TransactionGroup::Income),
+            (TransactionKind::Debit, TransactionGroup::Spending),
+            (TransactionKind::Payment, TransactionGroup::Spending),
+            (TransactionKind::Transfer, TransactionGroup::Transfer),
+            (TransactionKind::StandingOrder, TransactionGroup::Spending),
+            (TransactionKind::Eftpos, TransactionGroup::Spending),
+            (TransactionKind::Interest, TransactionGroup::Interest),
+            (TransactionKind::Fee, TransactionGroup::Fee),
+            (TransactionKind::Tax, TransactionGroup::Other),
+            (TransactionKind::CreditCard, TransactionGroup::Spending),
+            (TransactionKind::DirectDebit, TransactionGroup::Spending),
+            (TransactionKind::DirectCredit, TransactionGroup::Income),
+            (TransactionKind::Atm, TransactionGroup::Spending),
+            (TransactionKind::Loan, TransactionGroup::Other),
+        ];
+
+        for (kind, group) in cases {
+            assert_eq!(kind.group(), group, "unexpected group for {kind}");
+        }
+    }
+
+    fn conversion(amount: &str, rate: &str) -> TransactionConversion {
+        TransactionConversion {
+            amount: amount.parse().unwrap(),
+            currency: iso_currency::Currency::GBP,
+            rate: rate.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_conversion_rate_consistent() {
+        let conversion = conversion("10.00", "2.1234");
+        let tolerance = "0.01".parse().unwrap();
+
+        assert!(conversion.is_rate_consistent("-21.23".parse().unwrap(), tolerance));
+        assert!(conversion.is_rate_consistent("21.24".parse().unwrap(), tolerance));
+    }
+
+    #[test]
+    fn test_conversion_rate_inconsistent() {
+        let conversion = conversion("10.00", "2.1234");
+        let tolerance = "0.01".parse().unwrap();
+
+        assert!(!conversion.is_rate_consistent("-25.00".parse().unwrap(), tolerance));
+        assert!(!conversion.is_rate_consistent("-21.20".parse().unwrap(), tolerance));
+    }
+}
@@ -0,0 +1,288 @@
+//! Checks that every model field is read from the key Akahu actually sends.
+//!
+//! Many fields are renamed from their wire names (`_id`, `_account`, `type`,
+//! ...), so each test deserializes a fully-populated, real-shaped payload and
+//! asserts that every field was populated. A missing or misspelt `rename`
+//! shows up here as an empty optional field rather than silently in production.
+
+#![allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+
+use rust_decimal::Decimal;
+
+use crate::{
+    Account, Active, Address, AddressKind, Attribute, BankAccountKind, IdentityAccount, Party,
+    PendingTransaction, Transaction, TransactionKind, User,
+};
+
+fn timestamp(value: &str) -> chrono::DateTime<chrono::Utc> {
+    value.parse().unwrap()
+}
+
+#[test]
+#[allow(deprecated, reason = "The deprecated `_credentials` key is still sent")]
+fn test_account_wire_fields() {
+    let account: Account = serde_json::from_value(serde_json::json!({
+        "_id": "acc_123",
+        "_migrated": "acc_old",
+        "_authorisation": "auth_123",
+        "_credentials": "auth_123",
+        "name": "Everyday",
+        "status": "ACTIVE",
+        "formatted_account": "12-3456-7890123-00",
+        "refreshed": {
+            "balance": "2025-01-01T01:00:00.000Z",
+            "meta": "2025-01-01T02:00:00.000Z",
+            "transactions": "2025-01-01T03:00:00.000Z",
+            "party": "2025-01-01T04:00:00.000Z"
+        },
+        "balance": {
+            "current": -100.5,
+            "available": 900.25,
+            "limit": 1000,
+            "overdrawn": true,
+            "currency": "NZD"
+        },
+        "type": "CHECKING",
+        "attributes": ["TRANSACTIONS", "PAYMENT_FROM"]
+    }))
+    .unwrap();
+
+    assert_eq!(account.id.as_str(), "acc_123");
+    assert_eq!(account.migrated.as_deref(), Some("acc_old"));
+    assert_eq!(account.authorisation.as_str(), "auth_123");
+    assert_eq!(
+        account.credentials.as_ref().map(|id| id.as_str()),
+        Some("auth_123")
+    );
+    assert_eq!(account.name, "Everyday");
+    assert_eq!(account.status, Active::Active);
+    assert_eq!(
+        account.formatted_acount.as_deref(),
+        Some("12-3456-7890123-00")
+    );
+    assert_eq!(
+        account.refreshed.balance,
+        Some(timestamp("2025-01-01T01:00:00Z"))
+    );
+    assert_eq!(
+        account.refreshed.meta,
+        Some(timestamp("2025-01-01T02:00:00Z"))
+    );
+    assert_eq!(
+        account.refreshed.transactions,
+        Some(timestamp("2025-01-01T03:00:00Z"))
+    );
+    assert_eq!(
+        account.refreshed.party,
+        Some(timestamp("2025-01-01T04:00:00Z"))
+    );
+    assert_eq!(account.balance.current, Decimal::new(-1005, 1));
+    assert_eq!(account.balance.available, Some(Decimal::new(90025, 2)));
+    assert_eq!(account.balance.limit, Some(Decimal::new(1000, 0)));
+    assert_eq!(account.balance.overdrawn, Some(true));
+    assert_eq!(account.balance.currency, iso_currency::Currency::NZD);
+    assert_eq!(account.kind, BankAccountKind::Checking);
+    assert_eq!(
+        account.attributes,
+        vec![Attribute::Transactions, Attribute::PaymentFrom]
+    );
+}
+
+#[test]
+fn test_transaction_wire_fields() {
+    let transaction = Transaction::from_value_strict(serde_json::json!({
+        "_id": "trans_123",
+        "_account": "acc_123",
+        "_connection": "conn_123",
+        "created_at": "2025-01-02T00:00:00.000Z",
+        "updated_at": "2025-01-03T00:00:00.000Z",
+        "date": "2025-01-01T00:00:00.000Z",
+        "description": "THE WAREHOUSE",
+        "amount": -12.5,
+        "balance": 87.5,
+        "type": "EFTPOS",
+        "merchant": {
+            "_id": "_merchant_123",
+            "name": "The Warehouse",
+            "website": "https://www.thewarehouse.co.nz/"
+        },
+        "category": {
+            "_id": "cat_123",
+            "name": "General retail stores",
+            "groups": { "personal_finance": { "_id": "group_123", "name": "Lifestyle" } }
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(transaction.id.as_str(), "trans_123");
+    assert_eq!(transaction.account.as_str(), "acc_123");
+    assert_eq!(transaction.connection.as_str(), "conn_123");
+    assert_eq!(transaction.created_at, timestamp("2025-01-02T00:00:00Z"));
+    assert_eq!(
+        transaction.updated_at,
+        Some(timestamp("2025-01-03T00:00:00Z"))
+    );
+    assert_eq!(transaction.date, timestamp("2025-01-01T00:00:00Z"));
+    assert_eq!(transaction.description, "THE WAREHOUSE");
+    assert_eq!(transaction.amount, Decimal::new(-125, 1));
+    assert_eq!(transaction.balance, Some(Decimal::new(875, 1)));
+    assert_eq!(transaction.kind, TransactionKind::Eftpos);
+
+    let enriched = transaction.enriched_data.unwrap();
+    assert_eq!(enriched.merchant.id.as_str(), "_merchant_123");
+    assert_eq!(enriched.merchant.name, "The Warehouse");
+    assert_eq!(
+        enriched.merchant.website.as_ref().map(url::Url::as_str),
+        Some("https://www.thewarehouse.co.nz/")
+    );
+    assert_eq!(enriched.category.id.as_str(), "cat_123");
+    assert_eq!(
+        enriched.category.groups.personal_finance.id.as_str(),
+        "group_123"
+    );
+}
+
+#[test]
+fn test_pending_transaction_wire_fields() {
+    let pending: PendingTransaction = serde_json::from_value(serde_json::json!({
+        "_account": "acc_123",
+        "_connection": "conn_123",
+        "updated_at": "2025-01-02T00:00:00.000Z",
+        "date": "2025-01-01T00:00:00.000Z",
+        "description": "COUNTDOWN",
+        "amount": -45.2,
+        "type": "DEBIT",
+        "particulars": "PART",
+        "code": "CODE",
+        "reference": "REF",
+        "card_suffix": "1234"
+    }))
+    .unwrap();
+
+    assert_eq!(pending.account.as_str(), "acc_123");
+    assert_eq!(pending.connection.as_str(), "conn_123");
+    assert_eq!(pending.updated_at, timestamp("2025-01-02T00:00:00Z"));
+    assert_eq!(pending.date, timestamp("2025-01-01T00:00:00Z"));
+    assert_eq!(pending.description, "COUNTDOWN");
+    assert_eq!(pending.amount, Decimal::new(-452, 1));
+    assert_eq!(pending.kind, TransactionKind::Debit);
+
+    let meta = pending.meta.unwrap();
+    assert_eq!(meta.particulars.as_deref(), Some("PART"));
+    assert_eq!(meta.code.as_deref(), Some("CODE"));
+    assert_eq!(meta.reference.as_deref(), Some("REF"));
+    assert_eq!(meta.card_suffix.as_deref(), Some("1234"));
+}
+
+#[test]
+fn test_user_wire_fields() {
+    let user: User = serde_json::from_value(serde_json::json!({
+        "_id": "user_123",
+        "created_at": "2025-01-01T00:00:00.000Z",
+        "first_name": "Jane",
+        "last_name": "Smith",
+        "email": "jane@example.com",
+        "access_granted_at": "2025-01-02T00:00:00.000Z"
+    }))
+    .unwrap();
+
+    assert_eq!(user.id.as_str(), "user_123");
+    assert_eq!(user.created_at, timestamp("2025-01-01T00:00:00Z"));
+    assert_eq!(user.first_name.as_deref(), Some("Jane"));
+    assert_eq!(user.last_name.as_deref(), Some("Smith"));
+    assert_eq!(user.email.as_deref(), Some("jane@example.com"));
+    assert_eq!(
+        user.access_granted_at,
+        Some(timestamp("2025-01-02T00:00:00Z"))
+    );
+}
+
+#[test]
+fn test_identity_account_wire_fields() {
+    let account: IdentityAccount = serde_json::from_value(serde_json::json!({
+        "name": "Everyday",
+        "account_number": "12-3456-7890123-000",
+        "holder": "J SMITH",
+        "has_unlisted_holders": true,
+        "address": "1 Queen Street, Auckland",
+        "bank": "ASB",
+        "branch": {
+            "_id": "bank_branch_123",
+            "description": "Queen Street",
+            "phone": "+6491234567",
+            "address": "1 Queen Street, Auckland"
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(account.name, "Everyday");
+    assert!(account.account_number.bank_account().is_some());
+    assert_eq!(account.holder, "J SMITH");
+    assert!(account.has_unlisted_holders);
+    assert_eq!(account.address.as_deref(), Some("1 Queen Street, Auckland"));
+    assert_eq!(account.bank, "ASB");
+
+    let branch = account.branch.unwrap();
+    assert_eq!(branch.id.as_str(), "bank_branch_123");
+    assert_eq!(branch.description, "Queen Street");
+    assert_eq!(branch.phone.as_deref(), Some("+6491234567"));
+    assert_eq!(branch.address.as_deref(), Some("1 Queen Street, Auckland"));
+}
+
+#[test]
+fn test_party_wire_fields() {
+    let party: Party = serde_json::from_value(serde_json::json!({
+        "_id": "party_123",
+        "name": "Jane Smith",
+        "email": "jane@example.com",
+        "phone": "+6421234567",
+        "tax_number": "123-456-789",
+        "addresses": [{
+            "type": "RESIDENTIAL",
+            "value": "1 Queen Street, Auckland",
+            "formatted_address": "1 Queen Street, Auckland CBD, Auckland 1010, New Zealand",
+            "place_id": "ChIJ123",
+            "components": {
+                "street": "1 Queen Street",
+                "suburb": "Auckland CBD",
+                "city": "Auckland",
+                "region": "Auckland",
+                "postal_code": "1010",
+                "country": "NZ"
+            }
+        }],
+        "meta": { "source": "bank" }
+    }))
+    .unwrap();
+
+    assert_eq!(party.id, "party_123");
+    assert_eq!(party.name, "Jane Smith");
+    assert_eq!(party.email.as_deref(), Some("jane@example.com"));
+    assert_eq!(party.phone.as_deref(), Some("+6421234567"));
+    assert_eq!(party.tax_number.as_deref(), Some("123-456-789"));
+    assert_eq!(party.meta.get_string("source"), Some("bank"));
+
+    let addresses: Vec<Address> = party.addresses.unwrap();
+    let address = addresses.first().unwrap();
+    assert_eq!(address.kind, AddressKind::Residential);
+    assert_eq!(address.value, "1 Queen Street, Auckland");
+    assert!(address.formatted_address.is_some());
+    assert_eq!(address.place_id.as_deref(), Some("ChIJ123"));
+
+    let components = address.components.as_ref().unwrap();
+    assert_eq!(components.street.as_deref(), Some("1 Queen Street"));
+    assert_eq!(components.suburb.as_deref(), Some("Auckland CBD"));
+    assert_eq!(components.city.as_deref(), Some("Auckland"));
+    assert_eq!(components.region.as_deref(), Some("Auckland"));
+    assert_eq!(components.postal_code.as_deref(), Some("1010"));
+    assert_eq!(
+        components
+            .country
+            .as_ref()
+            .and_then(crate::AddressCountry::country),
+        Some(iso_currency::Country::NZ)
+    );
+}
@@ -0,0 +1,51 @@
+//! Helpers for presenting monetary amounts.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Format an amount to the minor-unit precision of its currency.
+///
+/// Amounts deserialized with arbitrary precision keep whatever scale Akahu sent
+/// (e.g. `1.2` or `1.200`), which makes raw output inconsistent. This rounds
+/// half away from zero to the currency's number of decimal places (2 for NZD)
+/// and always prints in plain decimal notation, e.g. `"1.20"`.
+///
+/// Currencies without a defined minor unit fall back to 2 decimal places.
+pub fn format_money(amount: Decimal, currency: iso_currency::Currency) -> String {
+    let decimal_places = currency.exponent().map_or(2, u32::from);
+    let rounded =
+        amount.round_dp_with_strategy(decimal_places, RoundingStrategy::MidpointAwayFromZero);
+    let precision = decimal_places as usize;
+    format!("{rounded:.precision$}")
+}
+
+#[cfg(test)]
+#[allow(
+    clippy::unwrap_used,
+    reason = "Tests need to unwrap to verify correctness"
+)]
+mod tests {
+    use super::*;
+    use iso_currency::Currency;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_format_money_normalises_scale() {
+        let amount = |s| Decimal::from_str(s).unwrap();
+
+        assert_eq!(format_money(amount("1.2"), Currency::NZD), "1.20");
+        assert_eq!(format_money(amount("1.200"), Currency::NZD), "1.20");
+        assert_eq!(format_money(amount("-0.005"), Currency::NZD), "-0.01");
+        assert_eq!(
+            format_money(amount("123456789012.3456"), Currency::NZD),
+            "123456789012.35"
+        );
+    }
+
+    #[test]
+    fn test_format_money_uses_currency_exponent() {
+        let amount = Decimal::from_str("1500.5").unwrap();
+
+        assert_eq!(format_money(amount, Currency::JPY), "1501");
+        assert_eq!(format_money(amount, Currency::BHD), "1500.500");
+    }
+}
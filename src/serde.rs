@@ -24,3 +24,119 @@ pub mod space_separated_strings_as_vec {
         Ok(s.map(|s| s.split_whitespace().map(String::from).collect()))
     }
 }
+
+/// Deserialize a type that only accepts borrowed strings from owned input too.
+///
+/// The `nzfcc` types deserialize via `&str`, which fails whenever the input
+/// cannot lend out its strings (e.g. a `serde_json::Value`, or the buffered
+/// content used by `#[serde(flatten)]`). Buffering into a `String` first makes
+/// them work with any deserializer.
+pub mod via_owned_str {
+    use serde::{
+        Deserialize,
+        de::{Deserializer, value::BorrowedStrDeserializer},
+    };
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: for<'a> Deserialize<'a>,
+    {
+        let s = String::deserialize(deserializer)?;
+        T::deserialize(BorrowedStrDeserializer::<D::Error>::new(&s))
+    }
+}
+
+/// Deserialize an ISO 4217 currency code case-insensitively.
+///
+/// Use together with `default = "currency_code::default_nzd"` for fields that
+/// some integrations omit.
+pub mod currency_code {
+    use iso_currency::Currency;
+    use serde::{Deserialize, de::Deserializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        Currency::from_code(&code.to_ascii_uppercase()).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&code),
+                &"an ISO 4217 currency code",
+            )
+        })
+    }
+
+    pub fn default_nzd() -> Currency {
+        tracing::warn!("balance is missing a currency, assuming NZD");
+        Currency::NZD
+    }
+}
+
+/// Deserialize either a single value or an array of values into a `Vec`.
+///
+/// Most list endpoints return an array, but some legacy responses collapse a
+/// single result into a bare object. Arrays are read element by element and a
+/// single object becomes a one-item `Vec`, so neither form is buffered.
+pub mod one_or_many {
+    use std::marker::PhantomData;
+
+    use serde::{
+        Deserialize,
+        de::{
+            Deserializer, MapAccess, SeqAccess, Visitor,
+            value::{MapAccessDeserializer, SeqAccessDeserializer},
+        },
+    };
+
+    struct OneOrManyVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for OneOrManyVisitor<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("an object or an array of objects")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            Vec::deserialize(SeqAccessDeserializer::new(seq))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            T::deserialize(MapAccessDeserializer::new(map)).map(|item| vec![item])
+        }
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_any(OneOrManyVisitor(PhantomData))
+    }
+}
+
+/// Implement `Deserialize` for a unit enum by matching its `FromStr` impl
+/// case-insensitively.
+///
+/// Akahu occasionally returns mixed case values (`"CreditCard"` rather than
+/// `"CREDITCARD"`). The input is uppercased before parsing, so the enum's
+/// `FromStr` must accept its canonical uppercase form. Serialization is
+/// unaffected and keeps using the derived, canonical casing.
+macro_rules! deserialize_case_insensitive {
+    ($name:ident, $expected:literal) => {
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+                value.to_ascii_uppercase().parse().map_err(|()| {
+                    serde::de::Error::invalid_value(serde::de::Unexpected::Str(&value), &$expected)
+                })
+            }
+        }
+    };
+}
+pub(crate) use deserialize_case_insensitive;
@@ -0,0 +1,90 @@
+//! Shared fixtures for unit tests.
+
+use wiremock::MockServer;
+
+use crate::{AkahuClient, UserToken};
+
+/// App token used by [`mock_client`].
+pub const APP_TOKEN: &str = "app_token_123";
+
+/// User token used by tests talking to a mock server.
+pub fn user_token() -> UserToken {
+    UserToken::new("user_token_123")
+}
+
+/// Create a client pointed at the given mock server.
+pub fn mock_client(server: &MockServer) -> AkahuClient {
+    AkahuClient::new(reqwest::Client::new(), APP_TOKEN, Some(server.uri()))
+}
+
+/// A minimal settled transaction as returned by the Akahu API.
+pub fn transaction_json(id: &str, date: &str, amount: f64) -> serde_json::Value {
+    serde_json::json!({
+        "_id": id,
+        "_account": "acc_123",
+        "_connection": "conn_123",
+        "created_at": date,
+        "date": date,
+        "description": "TEST TRANSACTION",
+        "amount": amount,
+        "type": if amount < 0.0 { "DEBIT" } else { "CREDIT" },
+    })
+}
+
+/// A minimal active checking account as returned by the Akahu API.
+pub fn account_json(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "_id": id,
+        "_authorisation": "auth_123",
+        "name": "Everyday",
+        "status": "ACTIVE",
+        "refreshed": {},
+        "balance": { "current": 100.5, "currency": "NZD" },
+        "type": "CHECKING",
+    })
+}
+
+/// A settled transaction enriched with a merchant and a retail category.
+///
+/// The merchant ID is derived from its name, so transactions from merchants
+/// with the same name share an ID.
+pub fn enriched_transaction_json(
+    id: &str,
+    date: &str,
+    amount: f64,
+    merchant: &str,
+) -> serde_json::Value {
+    let mut json = transaction_json(id, date, amount);
+    if let Some(object) = json.as_object_mut() {
+        object.insert(
+            "merchant".to_string(),
+            serde_json::json!({
+                "_id": format!("_merchant_{}", merchant.to_lowercase().replace(' ', "_")),
+                "name": merchant,
+                "website": null,
+            }),
+        );
+        object.insert(
+            "category".to_string(),
+            serde_json::json!({
+                "_id": "cat_123",
+                "name": "General retail stores",
+                "groups": { "personal_finance": { "_id": "group_123", "name": "Lifestyle" } }
+            }),
+        );
+    }
+    json
+}
+
+/// A minimal pending transaction as returned by the Akahu API.
+pub fn pending_transaction_json(date: &str, amount: f64) -> serde_json::Value {
+    serde_json::json!({
+        "_account": "acc_123",
+        "_connection": "conn_123",
+        "updated_at": date,
+        "date": date,
+        "description": "PENDING TRANSACTION",
+        "amount": amount,
+        "type": if amount < 0.0 { "DEBIT" } else { "CREDIT" },
+    })
+}
@@ -166,6 +166,16 @@ pub struct InvalidIdError {
 #[error("Invalid email address: '{0}'")]
 pub struct InvalidEmailError(pub String);
 
+/// Error when a redirect URI is not an absolute HTTPS URL
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Invalid redirect URI '{uri}': {reason}")]
+pub struct InvalidRedirectUri {
+    /// The URI that was provided
+    pub uri: String,
+    /// Why the URI was rejected
+    pub reason: &'static str,
+}
+
 // ============================================================================
 // Authentication & Authorization Types
 // ============================================================================
@@ -202,6 +212,30 @@ newtype_string!(
     pub ClientSecret
 );
 
+/// Byte access for secrets, e.g. as an HMAC key.
+///
+/// This is deliberately limited to secrets that are used as key material.
+macro_rules! secret_bytes {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Get the secret as bytes.
+                pub fn as_bytes(&self) -> &[u8] {
+                    self.0.as_bytes()
+                }
+            }
+
+            impl AsRef<[u8]> for $name {
+                fn as_ref(&self) -> &[u8] {
+                    self.as_bytes()
+                }
+            }
+        )+
+    };
+}
+
+secret_bytes!(AppSecret, ClientSecret);
+
 newtype_string!(
     /// OAuth authorization code (short-lived, valid for ~60 seconds).
     ///
@@ -217,6 +251,36 @@ newtype_string!(
     pub RedirectUri
 );
 
+impl RedirectUri {
+    /// Create a redirect URI, checking it is an absolute `https` URL.
+    ///
+    /// Plain `http` is accepted for `localhost`, `127.0.0.1` and `[::1]` so local
+    /// development servers can be used. Use [`new`](Self::new) to skip validation.
+    pub fn parse(value: &str) -> Result<Self, InvalidRedirectUri> {
+        let invalid = |reason| InvalidRedirectUri {
+            uri: value.to_string(),
+            reason,
+        };
+
+        let url = url::Url::parse(value).map_err(|_| invalid("not an absolute URL"))?;
+        let is_loopback = matches!(
+            url.host(),
+            Some(
+                url::Host::Domain("localhost")
+                    | url::Host::Ipv4(std::net::Ipv4Addr::LOCALHOST)
+                    | url::Host::Ipv6(std::net::Ipv6Addr::LOCALHOST)
+            )
+        );
+
+        match url.scheme() {
+            "https" => Ok(Self::new(value)),
+            "http" if is_loopback => Ok(Self::new(value)),
+            "http" => Err(invalid("http is only allowed for localhost, use https")),
+            _ => Err(invalid("scheme must be https")),
+        }
+    }
+}
+
 // ============================================================================
 // Resource Identifiers with Validation
 // ============================================================================
@@ -229,6 +293,14 @@ newtype_id!(
     "acc_"
 );
 
+/// Lets account methods accept either an [`AccountId`] or an
+/// [`Account`](crate::Account).
+impl AsRef<Self> for AccountId {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
 newtype_id!(
     /// Transaction identifier (always prefixed with `trans_`).
     ///
@@ -293,6 +365,22 @@ newtype_id!(
     "auth_"
 );
 
+newtype_id!(
+    /// OAuth profile identifier (always prefixed with `profile_`).
+    ///
+    /// Identifies the Akahu profile that completed an identity verification.
+    pub ProfileId,
+    "profile_"
+);
+
+newtype_id!(
+    /// Bank branch identifier (always prefixed with `bank_branch_`).
+    ///
+    /// Identifies the branch an account belongs to in identity results.
+    pub BankBranchId,
+    "bank_branch_"
+);
+
 // ============================================================================
 // Pagination & Query Types
 // ============================================================================
@@ -317,6 +405,21 @@ newtype_string!(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_redirect_uri_parse() {
+        let uri = RedirectUri::parse("https://example.com/callback").unwrap();
+        assert_eq!(uri.as_str(), "https://example.com/callback");
+
+        RedirectUri::parse("http://localhost:3000/callback").unwrap();
+        RedirectUri::parse("http://127.0.0.1:8080/callback").unwrap();
+
+        let error = RedirectUri::parse("http://example.com/callback").unwrap_err();
+        assert!(error.reason.contains("localhost"), "{error}");
+
+        let error = RedirectUri::parse("/callback").unwrap_err();
+        assert_eq!(error.reason, "not an absolute URL");
+    }
+
     #[test]
     fn test_account_id_validation() {
         // Valid account ID
@@ -346,4 +449,13 @@ mod tests {
         let token2: UserToken = "another_token".into();
         assert_eq!(token2.as_str(), "another_token");
     }
+
+    #[test]
+    fn test_secret_bytes() {
+        let app_secret = AppSecret::new("app_secret_123");
+        let client_secret = ClientSecret::new("client_secret_123");
+
+        assert_eq!(app_secret.as_bytes(), b"app_secret_123");
+        assert_eq!(AsRef::<[u8]>::as_ref(&client_secret), b"client_secret_123");
+    }
 }